@@ -0,0 +1,98 @@
+// Discord Rich Presence integration
+// Publishes "currently playing" presence while a launched game is running
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use discord_rich_presence::activity::{Activity, Assets, Timestamps};
+use discord_rich_presence::{DiscordIpc, DiscordIpcClient};
+
+use crate::ui::game_card::GameInfo;
+
+/// Winux's Discord application client ID
+const CLIENT_ID: &str = "1100000000000000000";
+
+/// Global on/off switch, flipped from the settings page
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+static CLIENT: Mutex<Option<DiscordIpcClient>> = Mutex::new(None);
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        stop();
+    }
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Publish presence for `game`. No-op (and no error spam) if disabled or if
+/// no Discord client is reachable.
+pub fn start(game: &GameInfo) {
+    if !is_enabled() {
+        return;
+    }
+
+    let Ok(mut guard) = CLIENT.lock() else {
+        return;
+    };
+
+    let mut client = match DiscordIpcClient::new(CLIENT_ID) {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::debug!("Discord RPC unavailable: {e}");
+            return;
+        }
+    };
+
+    if let Err(e) = client.connect() {
+        tracing::debug!("No Discord client reachable: {e}");
+        return;
+    }
+
+    let large_image = large_image_key(game);
+    let state = game.platform.display_name();
+    let now = now_unix_seconds();
+
+    let activity = Activity::new()
+        .details(&game.name)
+        .state(state)
+        .timestamps(Timestamps::new().start(now))
+        .assets(Assets::new().large_image(&large_image));
+
+    if let Err(e) = client.set_activity(activity) {
+        tracing::debug!("Failed to set Discord activity: {e}");
+        return;
+    }
+
+    *guard = Some(client);
+}
+
+/// Clear presence, e.g. when the game process exits
+pub fn stop() {
+    let Ok(mut guard) = CLIENT.lock() else {
+        return;
+    };
+
+    if let Some(mut client) = guard.take() {
+        let _ = client.clear_activity();
+        let _ = client.close();
+    }
+}
+
+fn large_image_key(game: &GameInfo) -> String {
+    if !game.cover_icon.is_empty() {
+        game.cover_icon.to_lowercase()
+    } else {
+        game.platform.display_name().to_lowercase()
+    }
+}
+
+fn now_unix_seconds() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,100 @@
+// Game log capture
+// Pipes a launched game's stdout/stderr into a size-capped, rotating game.log
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Child, Stdio};
+
+use anyhow::Result;
+
+/// Default cap on `game.log`'s size before it rolls over, in bytes (4 MiB)
+const DEFAULT_LOG_LIMIT_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Prepare `command`'s stdio so its output can be captured once spawned
+pub fn pipe_output(command: &mut std::process::Command) {
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+}
+
+/// Spawn background threads that copy `child`'s stdout/stderr into `game.log`,
+/// prefixed with a header identifying `game_id`/`game_name`.
+pub fn capture(child: &mut Child, game_id: &str, game_name: &str) {
+    write_header(game_id, game_name);
+
+    if let Some(stdout) = child.stdout.take() {
+        spawn_reader(stdout, "stdout");
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_reader(stderr, "stderr");
+    }
+}
+
+fn spawn_reader(pipe: impl std::io::Read + Send + 'static, stream: &'static str) {
+    std::thread::spawn(move || {
+        let reader = BufReader::new(pipe);
+        for line in reader.lines().map_while(Result::ok) {
+            append_line(&format!("[{stream}] {line}"));
+        }
+    });
+}
+
+fn write_header(game_id: &str, game_name: &str) {
+    append_line(&format!("===== {game_name} ({game_id}) ====="));
+}
+
+fn append_line(line: &str) {
+    rotate_if_needed();
+
+    let Ok(mut file) = open_log_writer() else {
+        return;
+    };
+    let _ = writeln!(file, "{line}");
+}
+
+/// Roll `game.log` to `game.log.1` if it has grown past the configured limit
+fn rotate_if_needed() {
+    let path = log_path();
+    let Ok(metadata) = fs::metadata(&path) else {
+        return;
+    };
+
+    if metadata.len() >= log_limit_bytes() {
+        let rolled = path.with_extension("log.1");
+        let _ = fs::rename(&path, rolled);
+    }
+}
+
+fn open_log_writer() -> std::io::Result<File> {
+    let path = log_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Read the limit from `WINUX_GAME_LOG_LIMIT` (bytes), defaulting to 4 MiB
+fn log_limit_bytes() -> u64 {
+    std::env::var("WINUX_GAME_LOG_LIMIT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_LOG_LIMIT_BYTES)
+}
+
+/// Path to the captured game log
+pub fn log_path() -> PathBuf {
+    directories::ProjectDirs::from("org", "winux", "gaming")
+        .map(|dirs| dirs.data_dir().join("game.log"))
+        .unwrap_or_else(|| {
+            let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+            PathBuf::from(home).join(".local/share/winux/gaming/game.log")
+        })
+}
+
+/// Open `game.log` in the user's default text viewer
+pub fn open_log() -> Result<()> {
+    std::process::Command::new("xdg-open")
+        .arg(log_path())
+        .spawn()?;
+    Ok(())
+}
@@ -0,0 +1,112 @@
+// Game launch subsystem
+// Builds and spawns the correct command line for a game, keyed on its Platform
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+use crate::discord_rpc;
+use crate::game_log;
+use crate::ui::game_card::{GameInfo, Platform};
+
+/// Per-game gamescope wrapping options
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GamescopeOptions {
+    /// Whether to wrap the launch command in gamescope at all
+    pub enabled: bool,
+    /// Extra flags passed to gamescope verbatim (e.g. `-W 1920 -H 1080`)
+    pub args: Vec<String>,
+    /// Force gamescope to grab the cursor (`--force-grab-cursor`)
+    pub force_grab_cursor: bool,
+}
+
+/// Per-game launch options: Wine/Proton environment and gamescope wrapping
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LaunchOptions {
+    /// Custom `WINEPREFIX` for Wine/Proton titles
+    pub wine_prefix: Option<PathBuf>,
+    /// Wine language/locale override, exported as `LANG`/`LC_ALL`
+    pub wine_locale: Option<String>,
+    /// gamescope wrapping
+    pub gamescope: GamescopeOptions,
+    /// Emulator core to use when `Platform::Emulator`
+    pub emulator_core: Option<String>,
+}
+
+/// Build the argv (and environment) needed to launch `game`, without spawning it
+fn build_command(game: &GameInfo) -> Result<Command> {
+    let opts = &game.launch_options;
+
+    let mut inner_argv: Vec<String> = match game.platform {
+        Platform::Steam => vec![
+            "steam".to_string(),
+            format!("steam://rungameid/{}", game.id),
+        ],
+        Platform::Lutris => vec!["lutris".to_string(), format!("lutris:rungameid/{}", game.id)],
+        Platform::GOG | Platform::Epic | Platform::Native => {
+            if game.id.is_empty() {
+                bail!("game '{}' has no executable configured", game.name);
+            }
+            vec![game.id.clone()]
+        }
+        Platform::Emulator => {
+            let core = opts
+                .emulator_core
+                .as_ref()
+                .with_context(|| format!("no emulator core configured for '{}'", game.name))?;
+            vec![core.clone(), game.id.clone()]
+        }
+    };
+
+    if opts.gamescope.enabled {
+        let mut argv = vec!["gamescope".to_string()];
+        argv.extend(opts.gamescope.args.iter().cloned());
+        if opts.gamescope.force_grab_cursor {
+            argv.push("--force-grab-cursor".to_string());
+        }
+        argv.push("--".to_string());
+        argv.append(&mut inner_argv);
+        inner_argv = argv;
+    }
+
+    let mut command = Command::new(&inner_argv[0]);
+    command.args(&inner_argv[1..]);
+
+    if let Some(prefix) = &opts.wine_prefix {
+        command.env("WINEPREFIX", prefix);
+    }
+    if let Some(locale) = &opts.wine_locale {
+        command.env("LANG", locale);
+        command.env("LC_ALL", locale);
+    }
+
+    tracing::info!(argv = ?inner_argv, "Launching game '{}'", game.name);
+
+    Ok(command)
+}
+
+/// Launch `game`, spawning the resolved command line, capturing its
+/// stdout/stderr into the shared `game.log`, and publishing Discord Rich
+/// Presence for as long as the process runs.
+pub fn launch_game(game: &GameInfo) -> Result<()> {
+    let mut command = build_command(game)?;
+    game_log::pipe_output(&mut command);
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("failed to launch '{}'", game.name))?;
+
+    game_log::capture(&mut child, &game.id, &game.name);
+    discord_rpc::start(game);
+
+    let game_name = game.name.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = child.wait() {
+            tracing::warn!("Failed to wait on '{game_name}': {e}");
+        }
+        discord_rpc::stop();
+    });
+
+    Ok(())
+}
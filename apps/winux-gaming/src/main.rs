@@ -13,6 +13,10 @@
 mod window;
 mod pages;
 mod launchers;
+mod launch;
+mod game_log;
+mod discord_rpc;
+mod version;
 mod optimization;
 mod ui;
 
@@ -10,7 +10,7 @@ use libadwaita as adw;
 use adw::prelude::*;
 use adw::{ActionRow, PreferencesGroup, PreferencesPage};
 
-use crate::ui::game_card::{GameInfo, Platform, create_game_card};
+use crate::ui::game_card::{GameInfo, LaunchOptions, Platform, create_game_card};
 
 pub fn create_library_page() -> ScrolledWindow {
     let main_box = Box::builder()
@@ -269,6 +269,10 @@ fn get_recent_games() -> Vec<GameInfo> {
             last_played: Some("Hoje".to_string()),
             cover_icon: "CP".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "baldursgate3".to_string(),
@@ -279,6 +283,10 @@ fn get_recent_games() -> Vec<GameInfo> {
             last_played: Some("Ontem".to_string()),
             cover_icon: "BG".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "hollowknight".to_string(),
@@ -289,6 +297,10 @@ fn get_recent_games() -> Vec<GameInfo> {
             last_played: Some("2 dias".to_string()),
             cover_icon: "HK".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "rdr2".to_string(),
@@ -299,6 +311,10 @@ fn get_recent_games() -> Vec<GameInfo> {
             last_played: Some("3 dias".to_string()),
             cover_icon: "RD".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
     ]
 }
@@ -314,6 +330,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("1 semana".to_string()),
             cover_icon: "CE".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "deadcells".to_string(),
@@ -324,6 +344,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("2 semanas".to_string()),
             cover_icon: "DC".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "disco".to_string(),
@@ -334,6 +358,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("1 mes".to_string()),
             cover_icon: "DE".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "eldenring".to_string(),
@@ -344,6 +372,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("3 dias".to_string()),
             cover_icon: "ER".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "hades".to_string(),
@@ -354,6 +386,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("1 semana".to_string()),
             cover_icon: "HA".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "stardew".to_string(),
@@ -364,6 +400,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("5 dias".to_string()),
             cover_icon: "SV".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "terraria".to_string(),
@@ -374,6 +414,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: None,
             cover_icon: "TE".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "witcher3".to_string(),
@@ -384,6 +428,10 @@ fn get_all_games() -> Vec<GameInfo> {
             last_played: Some("2 meses".to_string()),
             cover_icon: "W3".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
     ]
 }
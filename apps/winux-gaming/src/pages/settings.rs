@@ -411,8 +411,11 @@ fn create_integrations_section() -> PreferencesGroup {
     let discord = SwitchRow::builder()
         .title("Discord Rich Presence")
         .subtitle("Mostrar jogo atual no Discord")
-        .active(true)
+        .active(crate::discord_rpc::is_enabled())
         .build();
+    discord.connect_active_notify(|row| {
+        crate::discord_rpc::set_enabled(row.is_active());
+    });
     group.add(&discord);
 
     group
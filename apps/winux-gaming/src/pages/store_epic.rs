@@ -10,7 +10,7 @@ use libadwaita as adw;
 use adw::prelude::*;
 use adw::{ActionRow, PreferencesGroup, PreferencesPage, SwitchRow};
 
-use crate::ui::game_card::{GameInfo, Platform, create_game_card};
+use crate::ui::game_card::{GameInfo, LaunchOptions, Platform, create_game_card};
 
 pub fn create_epic_page() -> ScrolledWindow {
     let main_box = Box::builder()
@@ -414,6 +414,10 @@ fn get_epic_games() -> Vec<GameInfo> {
             last_played: Some("3 dias".to_string()),
             cover_icon: "RD".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "hades".to_string(),
@@ -424,6 +428,10 @@ fn get_epic_games() -> Vec<GameInfo> {
             last_played: Some("1 semana".to_string()),
             cover_icon: "HA".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "control".to_string(),
@@ -434,6 +442,10 @@ fn get_epic_games() -> Vec<GameInfo> {
             last_played: None,
             cover_icon: "CT".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "gta5".to_string(),
@@ -444,6 +456,10 @@ fn get_epic_games() -> Vec<GameInfo> {
             last_played: Some("1 mes".to_string()),
             cover_icon: "GTA".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "alanwake".to_string(),
@@ -454,6 +470,10 @@ fn get_epic_games() -> Vec<GameInfo> {
             last_played: None,
             cover_icon: "AW".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "borderlands3".to_string(),
@@ -464,6 +484,10 @@ fn get_epic_games() -> Vec<GameInfo> {
             last_played: Some("2 semanas".to_string()),
             cover_icon: "B3".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
     ]
 }
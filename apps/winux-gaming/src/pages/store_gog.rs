@@ -10,7 +10,7 @@ use libadwaita as adw;
 use adw::prelude::*;
 use adw::{ActionRow, PreferencesGroup, PreferencesPage, StatusPage, SwitchRow};
 
-use crate::ui::game_card::{GameInfo, Platform, create_game_card};
+use crate::ui::game_card::{GameInfo, LaunchOptions, Platform, create_game_card};
 
 pub fn create_gog_page() -> ScrolledWindow {
     let main_box = Box::builder()
@@ -307,6 +307,10 @@ fn get_gog_games() -> Vec<GameInfo> {
             last_played: Some("2 meses".to_string()),
             cover_icon: "W3".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "disco".to_string(),
@@ -317,6 +321,10 @@ fn get_gog_games() -> Vec<GameInfo> {
             last_played: Some("1 mes".to_string()),
             cover_icon: "DE".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "hollowknight".to_string(),
@@ -327,6 +335,10 @@ fn get_gog_games() -> Vec<GameInfo> {
             last_played: Some("2 dias".to_string()),
             cover_icon: "HK".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "deadcells".to_string(),
@@ -337,6 +349,10 @@ fn get_gog_games() -> Vec<GameInfo> {
             last_played: Some("2 semanas".to_string()),
             cover_icon: "DC".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "divinity2".to_string(),
@@ -347,6 +363,10 @@ fn get_gog_games() -> Vec<GameInfo> {
             last_played: None,
             cover_icon: "D2".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "pathfinder".to_string(),
@@ -357,6 +377,10 @@ fn get_gog_games() -> Vec<GameInfo> {
             last_played: Some("1 semana".to_string()),
             cover_icon: "PF".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
     ]
 }
@@ -10,7 +10,7 @@ use libadwaita as adw;
 use adw::prelude::*;
 use adw::{ActionRow, ExpanderRow, PreferencesGroup, PreferencesPage, StatusPage, SwitchRow};
 
-use crate::ui::game_card::{GameInfo, Platform, create_game_card};
+use crate::ui::game_card::{GameInfo, LaunchOptions, Platform, create_game_card};
 
 pub fn create_steam_page() -> ScrolledWindow {
     let main_box = Box::builder()
@@ -317,6 +317,10 @@ fn get_steam_games() -> Vec<GameInfo> {
             last_played: Some("Hoje".to_string()),
             cover_icon: "CP".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "eldenring".to_string(),
@@ -327,6 +331,10 @@ fn get_steam_games() -> Vec<GameInfo> {
             last_played: Some("3 dias".to_string()),
             cover_icon: "ER".to_string(),
             native: false,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "baldursgate3".to_string(),
@@ -337,6 +345,10 @@ fn get_steam_games() -> Vec<GameInfo> {
             last_played: Some("Ontem".to_string()),
             cover_icon: "BG".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "celeste".to_string(),
@@ -347,6 +359,10 @@ fn get_steam_games() -> Vec<GameInfo> {
             last_played: Some("1 semana".to_string()),
             cover_icon: "CE".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "stardew".to_string(),
@@ -357,6 +373,10 @@ fn get_steam_games() -> Vec<GameInfo> {
             last_played: Some("5 dias".to_string()),
             cover_icon: "SV".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
         GameInfo {
             id: "hollowknight_steam".to_string(),
@@ -367,6 +387,10 @@ fn get_steam_games() -> Vec<GameInfo> {
             last_played: None,
             cover_icon: "HK".to_string(),
             native: true,
+            launch_options: LaunchOptions::default(),
+            install_dir: None,
+            installed_version: None,
+            latest_version: None,
         },
     ]
 }
@@ -1,8 +1,17 @@
 // Game card component - Steam Deck inspired
 // Large clickable cards with cover art and quick actions
 
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
 use gtk4::prelude::*;
-use gtk4::{Box, Button, Frame, Image, Label, Orientation, Overlay};
+use gtk4::{Box, Button, Entry, Frame, Image, Label, Orientation, Overlay, Popover};
+
+use crate::game_log;
+use crate::launch;
+pub use crate::launch::{GamescopeOptions, LaunchOptions};
+use crate::version;
 
 /// Game platform enumeration
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -61,6 +70,14 @@ pub struct GameInfo {
     pub last_played: Option<String>,
     pub cover_icon: String, // Placeholder icon text
     pub native: bool,
+    /// Wine prefix, locale and gamescope wrapping for this game's launches
+    pub launch_options: LaunchOptions,
+    /// Install directory, used to look up a local `.version` file
+    pub install_dir: Option<PathBuf>,
+    /// Version reported by the platform/manager (overridden by a local `.version` file, if present)
+    pub installed_version: Option<String>,
+    /// Latest version known to be available
+    pub latest_version: Option<String>,
 }
 
 impl GameInfo {
@@ -138,6 +155,19 @@ pub fn create_game_card(game: &GameInfo) -> Frame {
         cover_overlay.add_overlay(&native_badge);
     }
 
+    let has_update = game.installed && version::update_available(game);
+    if has_update {
+        let update_badge = Label::builder()
+            .label("Atualizar")
+            .css_classes(vec!["platform-badge", "platform-update"])
+            .halign(gtk4::Align::End)
+            .valign(gtk4::Align::End)
+            .margin_end(8)
+            .margin_bottom(8)
+            .build();
+        cover_overlay.add_overlay(&update_badge);
+    }
+
     content.append(&cover_overlay);
 
     // Game title
@@ -150,6 +180,15 @@ pub fn create_game_card(game: &GameInfo) -> Frame {
         .build();
     content.append(&title);
 
+    if let Some(installed_version) = version::effective_installed_version(game) {
+        let version_label = Label::builder()
+            .label(&format!("v{installed_version}"))
+            .css_classes(vec!["dim-label", "caption"])
+            .halign(gtk4::Align::Center)
+            .build();
+        content.append(&version_label);
+    }
+
     // Playtime and last played
     if game.installed {
         let info_box = Box::builder()
@@ -185,22 +224,54 @@ pub fn create_game_card(game: &GameInfo) -> Frame {
         content.append(&info_box);
     }
 
+    let game_state = Rc::new(RefCell::new(game.clone()));
+
     // Action button
-    let action_btn = if game.installed {
+    let actions_box = Box::builder()
+        .orientation(Orientation::Horizontal)
+        .spacing(4)
+        .halign(gtk4::Align::Center)
+        .build();
+
+    let action_btn = if has_update {
+        Button::builder()
+            .label("Atualizar")
+            .css_classes(vec!["install-button"])
+            .build()
+    } else if game.installed {
         Button::builder()
             .icon_name("media-playback-start-symbolic")
             .css_classes(vec!["suggested-action", "circular"])
             .tooltip_text("Jogar")
-            .halign(gtk4::Align::Center)
             .build()
     } else {
         Button::builder()
             .label("Instalar")
             .css_classes(vec!["install-button"])
-            .halign(gtk4::Align::Center)
             .build()
     };
-    content.append(&action_btn);
+    connect_play_or_install(&action_btn, &game_state, has_update);
+    actions_box.append(&action_btn);
+
+    if matches!(game.platform, Platform::Steam | Platform::Lutris) {
+        let settings_btn = Button::builder()
+            .icon_name("emblem-system-symbolic")
+            .css_classes(vec!["flat", "circular"])
+            .tooltip_text("Opcoes de lancamento")
+            .build();
+        attach_launch_options_popover(&settings_btn, &game_state);
+        actions_box.append(&settings_btn);
+    }
+
+    let more_btn = Button::builder()
+        .icon_name("view-more-symbolic")
+        .css_classes(vec!["flat", "circular"])
+        .tooltip_text("Mais opcoes")
+        .build();
+    attach_more_options_popover(&more_btn);
+    actions_box.append(&more_btn);
+
+    content.append(&actions_box);
 
     overlay.set_child(Some(&content));
 
@@ -208,6 +279,143 @@ pub fn create_game_card(game: &GameInfo) -> Frame {
     card
 }
 
+/// Attach a popover with a "Ver log" action that opens the captured `game.log`
+fn attach_more_options_popover(button: &Button) {
+    let popover = Popover::new();
+    popover.set_parent(button);
+
+    let view_log_btn = Button::builder()
+        .label("Ver log")
+        .css_classes(vec!["flat"])
+        .build();
+
+    let popover_clone = popover.clone();
+    view_log_btn.connect_clicked(move |_| {
+        if let Err(e) = game_log::open_log() {
+            tracing::warn!("Failed to open game.log: {e}");
+        }
+        popover_clone.popdown();
+    });
+
+    popover.set_child(Some(&view_log_btn));
+
+    let popover_clone = popover.clone();
+    button.connect_clicked(move |_| {
+        popover_clone.popup();
+    });
+}
+
+/// Wire a Play/Install/Update button to launch, install, or update the game held in `state`
+fn connect_play_or_install(button: &Button, state: &Rc<RefCell<GameInfo>>, has_update: bool) {
+    let state = state.clone();
+    button.connect_clicked(move |_| {
+        let game = state.borrow().clone();
+        if has_update {
+            tracing::info!("Updating '{}' via {}...", game.name, game.platform.display_name());
+        } else if game.installed {
+            if let Err(e) = launch::launch_game(&game) {
+                tracing::error!("Failed to launch '{}': {e}", game.name);
+            }
+        } else {
+            tracing::info!("Installing '{}' via {}...", game.name, game.platform.display_name());
+        }
+    });
+}
+
+/// Attach a popover letting the user edit `state`'s Wine prefix, locale and
+/// gamescope wrapping (only meaningful for Wine/Proton-capable platforms)
+fn attach_launch_options_popover(button: &Button, state: &Rc<RefCell<GameInfo>>) {
+    let popover = Popover::new();
+    popover.set_parent(button);
+
+    let content = Box::builder()
+        .orientation(Orientation::Vertical)
+        .spacing(8)
+        .margin_start(12)
+        .margin_end(12)
+        .margin_top(12)
+        .margin_bottom(12)
+        .width_request(260)
+        .build();
+
+    let opts = state.borrow().launch_options.clone();
+
+    let prefix_entry = Entry::new();
+    prefix_entry.set_placeholder_text(Some("WINEPREFIX (opcional)"));
+    if let Some(prefix) = &opts.wine_prefix {
+        prefix_entry.set_text(&prefix.display().to_string());
+    }
+    content.append(&Label::builder().label("Prefixo Wine").halign(gtk4::Align::Start).css_classes(vec!["caption", "dim-label"]).build());
+    content.append(&prefix_entry);
+
+    let locale_entry = Entry::new();
+    locale_entry.set_placeholder_text(Some("Locale (ex: pt_BR.UTF-8)"));
+    if let Some(locale) = &opts.wine_locale {
+        locale_entry.set_text(locale);
+    }
+    content.append(&Label::builder().label("Idioma do Wine").halign(gtk4::Align::Start).css_classes(vec!["caption", "dim-label"]).build());
+    content.append(&locale_entry);
+
+    let gamescope_check = gtk4::CheckButton::with_label("Usar gamescope");
+    gamescope_check.set_active(opts.gamescope.enabled);
+    content.append(&gamescope_check);
+
+    let grab_cursor_check = gtk4::CheckButton::with_label("Forcar captura do cursor");
+    grab_cursor_check.set_active(opts.gamescope.force_grab_cursor);
+    grab_cursor_check.set_sensitive(opts.gamescope.enabled);
+    content.append(&grab_cursor_check);
+
+    let grab_cursor_check_clone = grab_cursor_check.clone();
+    gamescope_check.connect_toggled(move |check| {
+        grab_cursor_check_clone.set_sensitive(check.is_active());
+    });
+
+    let gamescope_args_entry = Entry::new();
+    gamescope_args_entry.set_placeholder_text(Some("Flags extras do gamescope"));
+    gamescope_args_entry.set_text(&opts.gamescope.args.join(" "));
+    content.append(&gamescope_args_entry);
+
+    let save_btn = Button::with_label("Salvar");
+    save_btn.add_css_class("suggested-action");
+    content.append(&save_btn);
+
+    let state_clone = state.clone();
+    let popover_clone = popover.clone();
+    save_btn.connect_clicked(move |_| {
+        let wine_prefix = {
+            let text = prefix_entry.text();
+            (!text.is_empty()).then(|| std::path::PathBuf::from(text.as_str()))
+        };
+        let wine_locale = {
+            let text = locale_entry.text();
+            (!text.is_empty()).then(|| text.to_string())
+        };
+        let args = gamescope_args_entry
+            .text()
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+
+        let mut game = state_clone.borrow_mut();
+        game.launch_options.wine_prefix = wine_prefix;
+        game.launch_options.wine_locale = wine_locale;
+        game.launch_options.gamescope = GamescopeOptions {
+            enabled: gamescope_check.is_active(),
+            args,
+            force_grab_cursor: grab_cursor_check.is_active(),
+        };
+        tracing::info!("Updated launch options for '{}'", game.name);
+        popover_clone.popdown();
+    });
+
+    popover.set_child(Some(&content));
+
+    let popover_clone = popover.clone();
+    button.connect_clicked(move |_| {
+        popover_clone.popup();
+    });
+}
+
 /// Create a compact game card for lists
 pub fn create_game_row(game: &GameInfo) -> Box {
     let row = Box::builder()
@@ -287,8 +495,15 @@ pub fn create_game_row(game: &GameInfo) -> Box {
         row.append(&status);
     }
 
-    // Play/Install button
-    let action_btn = if game.installed {
+    // Play/Install/Update button
+    let has_update = game.installed && version::update_available(game);
+    let action_btn = if has_update {
+        Button::builder()
+            .label("Atualizar")
+            .css_classes(vec!["flat"])
+            .valign(gtk4::Align::Center)
+            .build()
+    } else if game.installed {
         Button::builder()
             .icon_name("media-playback-start-symbolic")
             .css_classes(vec!["suggested-action", "circular"])
@@ -301,6 +516,7 @@ pub fn create_game_row(game: &GameInfo) -> Box {
             .valign(gtk4::Align::Center)
             .build()
     };
+    connect_play_or_install(&action_btn, &Rc::new(RefCell::new(game.clone())), has_update);
     row.append(&action_btn);
 
     // More options button
@@ -309,6 +525,7 @@ pub fn create_game_row(game: &GameInfo) -> Box {
         .css_classes(vec!["flat"])
         .valign(gtk4::Align::Center)
         .build();
+    attach_more_options_popover(&more_btn);
     row.append(&more_btn);
 
     row
@@ -379,6 +596,15 @@ pub fn create_featured_banner(game: &GameInfo) -> Frame {
         platform_box.append(&native_badge);
     }
 
+    let has_update = game.installed && version::update_available(game);
+    if has_update {
+        let update_badge = Label::builder()
+            .label("Atualizar")
+            .css_classes(vec!["platform-badge", "platform-update"])
+            .build();
+        platform_box.append(&update_badge);
+    }
+
     info.append(&platform_box);
 
     // Playtime
@@ -391,6 +617,15 @@ pub fn create_featured_banner(game: &GameInfo) -> Frame {
         info.append(&playtime);
     }
 
+    if let Some(installed_version) = version::effective_installed_version(game) {
+        let version_label = Label::builder()
+            .label(&format!("Versao instalada: v{installed_version}"))
+            .css_classes(vec!["dim-label"])
+            .halign(gtk4::Align::Start)
+            .build();
+        info.append(&version_label);
+    }
+
     // Buttons
     let buttons_box = Box::builder()
         .orientation(Orientation::Horizontal)
@@ -399,9 +634,16 @@ pub fn create_featured_banner(game: &GameInfo) -> Frame {
         .build();
 
     let play_btn = Button::builder()
-        .label(if game.installed { "Jogar" } else { "Instalar" })
+        .label(if has_update {
+            "Atualizar"
+        } else if game.installed {
+            "Jogar"
+        } else {
+            "Instalar"
+        })
         .css_classes(vec!["play-button"])
         .build();
+    connect_play_or_install(&play_btn, &Rc::new(RefCell::new(game.clone())), has_update);
     buttons_box.append(&play_btn);
 
     let details_btn = Button::builder()
@@ -0,0 +1,72 @@
+// Game version tracking
+// Resolves the installed version of a game, preferring a local `.version`
+// file over any version reported by the platform/manager
+
+use std::path::Path;
+
+use crate::ui::game_card::GameInfo;
+
+/// Resolve the version actually installed for `game`.
+///
+/// A `.version` file at the root of the install directory always wins; it
+/// falls back to `game.installed_version` (typically populated from a
+/// platform/manager query, e.g. a Steam appmanifest's `buildid`) when no such
+/// file exists.
+pub fn effective_installed_version(game: &GameInfo) -> Option<String> {
+    if let Some(dir) = &game.install_dir {
+        if let Some(version) = read_version_file(dir) {
+            return Some(version);
+        }
+    }
+
+    game.installed_version.clone()
+}
+
+fn read_version_file(install_dir: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(install_dir.join(".version")).ok()?;
+    let version = contents.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// Whether `game` has a newer version available than the one installed
+pub fn update_available(game: &GameInfo) -> bool {
+    match (effective_installed_version(game), &game.latest_version) {
+        (Some(installed), Some(latest)) => version_less_than(&installed, latest),
+        _ => false,
+    }
+}
+
+/// Numeric dot-separated components of a version string, e.g. "1.2.3" ->
+/// `[1, 2, 3]`. Any non-numeric prefix/suffix on a component (e.g. a "v" or
+/// a build tag) is ignored; a component with no digits at all counts as `0`.
+fn version_components(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| {
+            part.chars()
+                .skip_while(|c| !c.is_ascii_digit())
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+/// Whether `installed` is a strictly older version than `latest`, comparing
+/// components numerically (so "1.0" == "1.0.0", and "1.9" < "1.10") rather
+/// than as strings.
+fn version_less_than(installed: &str, latest: &str) -> bool {
+    let installed = version_components(installed);
+    let latest = version_components(latest);
+
+    for i in 0..installed.len().max(latest.len()) {
+        let a = installed.get(i).copied().unwrap_or(0);
+        let b = latest.get(i).copied().unwrap_or(0);
+        if a != b {
+            return a < b;
+        }
+    }
+
+    false
+}
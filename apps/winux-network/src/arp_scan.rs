@@ -0,0 +1,214 @@
+//! ARP-based LAN device discovery
+//!
+//! Actively probes the local subnet by broadcasting ARP requests over the
+//! active interface's Ethernet channel and collecting replies, so the
+//! network tool can show what's actually on the LAN rather than only the
+//! local machine's own connections.
+
+use std::collections::HashMap;
+use std::net::Ipv4Addr;
+use std::time::{Duration, Instant};
+
+use ipnetwork::IpNetwork;
+use pnet::datalink::{self, Channel, NetworkInterface};
+use pnet::packet::arp::{ArpHardwareTypes, ArpOperations, ArpPacket, MutableArpPacket};
+use pnet::packet::ethernet::{EtherTypes, EthernetPacket, MutableEthernetPacket};
+use pnet::packet::{MutablePacket, Packet};
+use pnet::util::MacAddr;
+use thiserror::Error;
+
+/// How long to listen for ARP replies after the sweep is sent
+const DEFAULT_SCAN_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How often the receive loop checks the deadline
+const READ_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// ARP scan errors
+#[derive(Error, Debug)]
+pub enum ArpError {
+    #[error("network interface not found: {0}")]
+    InterfaceNotFound(String),
+
+    #[error("interface {0} has no MAC address")]
+    NoMacAddress(String),
+
+    #[error("interface {0} has no IPv4 address")]
+    NoIpv4Address(String),
+
+    #[error("failed to open datalink channel: {0}")]
+    Channel(String),
+
+    #[error("unsupported datalink channel type")]
+    UnsupportedChannel,
+}
+
+/// A host discovered by the ARP sweep
+#[derive(Debug, Clone)]
+pub struct DiscoveredHost {
+    pub ip: Ipv4Addr,
+    pub mac: String,
+    pub vendor: String,
+}
+
+/// Pick a reasonable default interface to scan from: the first interface
+/// that is up, isn't the loopback device, and has an IPv4 address.
+pub fn default_interface() -> Option<NetworkInterface> {
+    datalink::interfaces().into_iter().find(|iface| {
+        iface.is_up()
+            && !iface.is_loopback()
+            && iface.ips.iter().any(|ip| ip.is_ipv4())
+    })
+}
+
+/// Broadcast ARP requests across `interface_name`'s IPv4 subnet and collect
+/// replies for `timeout`, de-duplicating repeat responders by IP.
+///
+/// This performs blocking I/O and is meant to be run on a background
+/// thread.
+pub fn scan(interface_name: &str, timeout: Duration) -> Result<Vec<DiscoveredHost>, ArpError> {
+    let interface = datalink::interfaces()
+        .into_iter()
+        .find(|iface| iface.name == interface_name)
+        .ok_or_else(|| ArpError::InterfaceNotFound(interface_name.to_string()))?;
+
+    let source_mac = interface
+        .mac
+        .ok_or_else(|| ArpError::NoMacAddress(interface_name.to_string()))?;
+
+    let network = interface
+        .ips
+        .iter()
+        .find_map(|ip| match ip {
+            IpNetwork::V4(net) => Some(*net),
+            IpNetwork::V6(_) => None,
+        })
+        .ok_or_else(|| ArpError::NoIpv4Address(interface_name.to_string()))?;
+
+    let source_ip = network.ip();
+
+    let config = datalink::Config {
+        read_timeout: Some(READ_POLL_INTERVAL),
+        ..Default::default()
+    };
+
+    let (mut tx, mut rx) = match datalink::channel(&interface, config) {
+        Ok(Channel::Ethernet(tx, rx)) => (tx, rx),
+        Ok(_) => return Err(ArpError::UnsupportedChannel),
+        Err(e) => return Err(ArpError::Channel(e.to_string())),
+    };
+
+    for target_ip in network.iter() {
+        if target_ip == source_ip {
+            continue;
+        }
+        send_arp_request(tx.as_mut(), source_mac, source_ip, target_ip);
+    }
+
+    let mut hosts: HashMap<Ipv4Addr, DiscoveredHost> = HashMap::new();
+    let deadline = Instant::now() + timeout;
+
+    while Instant::now() < deadline {
+        match rx.next() {
+            Ok(frame) => {
+                if let Some(host) = parse_arp_reply(frame) {
+                    hosts.entry(host.ip).or_insert(host);
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => continue,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => continue,
+            Err(_) => break,
+        }
+    }
+
+    let mut hosts: Vec<DiscoveredHost> = hosts.into_values().collect();
+    hosts.sort_by_key(|host| host.ip);
+    Ok(hosts)
+}
+
+/// Build and send a single ARP "who-has" request
+fn send_arp_request(
+    tx: &mut dyn datalink::DataLinkSender,
+    source_mac: MacAddr,
+    source_ip: Ipv4Addr,
+    target_ip: Ipv4Addr,
+) {
+    let mut ethernet_buffer = [0u8; 42];
+    let Some(mut ethernet_packet) = MutableEthernetPacket::new(&mut ethernet_buffer) else {
+        return;
+    };
+
+    ethernet_packet.set_destination(MacAddr::broadcast());
+    ethernet_packet.set_source(source_mac);
+    ethernet_packet.set_ethertype(EtherTypes::Arp);
+
+    let mut arp_buffer = [0u8; 28];
+    let Some(mut arp_packet) = MutableArpPacket::new(&mut arp_buffer) else {
+        return;
+    };
+
+    arp_packet.set_hardware_type(ArpHardwareTypes::Ethernet);
+    arp_packet.set_protocol_type(EtherTypes::Ipv4);
+    arp_packet.set_hw_addr_len(6);
+    arp_packet.set_proto_addr_len(4);
+    arp_packet.set_operation(ArpOperations::Request);
+    arp_packet.set_sender_hw_addr(source_mac);
+    arp_packet.set_sender_proto_addr(source_ip);
+    arp_packet.set_target_hw_addr(MacAddr::zero());
+    arp_packet.set_target_proto_addr(target_ip);
+
+    ethernet_packet.set_payload(arp_packet.packet());
+
+    let _ = tx.send_to(ethernet_packet.packet(), None);
+}
+
+/// Parse a raw Ethernet frame and extract the sender of an ARP reply, if that's what it is
+fn parse_arp_reply(frame: &[u8]) -> Option<DiscoveredHost> {
+    let ethernet = EthernetPacket::new(frame)?;
+    if ethernet.get_ethertype() != EtherTypes::Arp {
+        return None;
+    }
+
+    let arp = ArpPacket::new(ethernet.payload())?;
+    if arp.get_operation() != ArpOperations::Reply {
+        return None;
+    }
+
+    let mac = arp.get_sender_hw_addr();
+    Some(DiscoveredHost {
+        ip: arp.get_sender_proto_addr(),
+        mac: mac.to_string(),
+        vendor: vendor_from_mac(&mac.to_string()),
+    })
+}
+
+/// A small table of well-known OUI prefixes; not an exhaustive vendor database
+const OUI_VENDORS: &[(&str, &str)] = &[
+    ("00:1A:2B", "Cisco"),
+    ("00:1B:63", "Apple"),
+    ("3C:22:FB", "Apple"),
+    ("F4:5C:89", "Apple"),
+    ("00:50:56", "VMware"),
+    ("08:00:27", "VirtualBox"),
+    ("52:54:00", "QEMU/KVM"),
+    ("DC:A6:32", "Raspberry Pi Foundation"),
+    ("B8:27:EB", "Raspberry Pi Foundation"),
+    ("A4:C3:F0", "Intel"),
+    ("FC:FB:FB", "Cisco"),
+    ("00:0C:29", "VMware"),
+];
+
+/// Resolve a vendor name from a MAC address's OUI (first three octets)
+fn vendor_from_mac(mac: &str) -> String {
+    let prefix: String = mac.split(':').take(3).collect::<Vec<_>>().join(":");
+
+    OUI_VENDORS
+        .iter()
+        .find(|(oui, _)| oui.eq_ignore_ascii_case(&prefix))
+        .map(|(_, vendor)| vendor.to_string())
+        .unwrap_or_else(|| "Desconhecido".to_string())
+}
+
+/// The default timeout used when the caller doesn't have a specific budget in mind
+pub fn default_scan_timeout() -> Duration {
+    DEFAULT_SCAN_TIMEOUT
+}
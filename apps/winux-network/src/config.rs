@@ -0,0 +1,69 @@
+//! Configuration management for Winux Network
+//!
+//! Handles loading and saving user preferences, such as known Wake-on-LAN hosts.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+use crate::wol::WolHost;
+
+/// Main configuration structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Known Wake-on-LAN hosts
+    #[serde(default)]
+    pub wol_hosts: Vec<WolHost>,
+}
+
+impl Config {
+    /// Load configuration from file
+    pub fn load() -> Self {
+        let config_path = Self::config_path();
+
+        if config_path.exists() {
+            match std::fs::read_to_string(&config_path) {
+                Ok(contents) => match toml::from_str(&contents) {
+                    Ok(config) => {
+                        info!("Configuration loaded from {:?}", config_path);
+                        return config;
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse config file: {}", e);
+                    }
+                },
+                Err(e) => {
+                    warn!("Failed to read config file: {}", e);
+                }
+            }
+        }
+
+        info!("Using default configuration");
+        Self::default()
+    }
+
+    /// Save configuration to file
+    pub fn save(&self) -> anyhow::Result<()> {
+        let config_path = Self::config_path();
+
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let contents = toml::to_string_pretty(self)?;
+        std::fs::write(&config_path, contents)?;
+
+        info!("Configuration saved to {:?}", config_path);
+        Ok(())
+    }
+
+    /// Get the configuration file path
+    fn config_path() -> PathBuf {
+        directories::ProjectDirs::from("org", "winux", "network")
+            .map(|dirs| dirs.config_dir().join("config.toml"))
+            .unwrap_or_else(|| {
+                let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+                PathBuf::from(home).join(".config/winux/network/config.toml")
+            })
+    }
+}
@@ -13,6 +13,9 @@ mod window;
 mod pages;
 mod nm;
 mod ui;
+mod wol;
+mod arp_scan;
+mod config;
 
 use gtk4::prelude::*;
 use gtk4::Application;
@@ -23,6 +23,7 @@ pub enum ScanEvent {
 }
 
 /// WiFi scanner for continuous or one-shot scanning
+#[derive(Clone)]
 pub struct WifiScanner {
     client: NetworkManagerClient,
     access_points: Arc<RwLock<Vec<AccessPoint>>>,
@@ -148,6 +149,16 @@ impl WifiScanner {
         self.access_points.read().await.clone()
     }
 
+    /// Connect to an access point found by this scanner
+    pub async fn connect(&self, ssid: &str, password: Option<&str>) -> NetworkResult<()> {
+        self.client.connect_wifi(ssid, password).await
+    }
+
+    /// Disconnect the active WiFi connection
+    pub async fn disconnect(&self) -> NetworkResult<()> {
+        self.client.disconnect_wifi().await
+    }
+
     /// Check if currently scanning
     pub async fn is_scanning(&self) -> bool {
         *self.is_scanning.read().await
@@ -239,6 +250,15 @@ pub fn frequency_to_channel(freq: u32) -> u32 {
     }
 }
 
+/// Convert a 0-100 signal quality percentage to an approximate dBm value
+///
+/// Uses the same linear approximation as NetworkManager's own tools
+/// (`dBm = quality / 2 - 100`), which is accurate enough for display
+/// purposes without needing the driver's raw RSSI.
+pub fn quality_to_dbm(quality: u8) -> i32 {
+    (quality as i32) / 2 - 100
+}
+
 /// Get band name from frequency
 pub fn frequency_to_band(freq: u32) -> &'static str {
     if freq >= 2412 && freq <= 2484 {
@@ -272,6 +292,13 @@ mod tests {
         assert_eq!(frequency_to_band(6115), "6 GHz");
     }
 
+    #[test]
+    fn test_quality_to_dbm() {
+        assert_eq!(quality_to_dbm(100), -50);
+        assert_eq!(quality_to_dbm(50), -75);
+        assert_eq!(quality_to_dbm(0), -100);
+    }
+
     #[test]
     fn test_parse_security() {
         assert_eq!(parse_security(0, 0, 0), WifiSecurity::None);
@@ -0,0 +1,152 @@
+//! LAN device discovery page
+//!
+//! Features:
+//! - Active ARP sweep of the local subnet
+//! - Table of live hosts: IP, MAC address, vendor (from the MAC OUI)
+//! - Runs on a background thread with a bounded timeout
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::{Image, ScrolledWindow, Spinner};
+use libadwaita as adw;
+use adw::prelude::*;
+use adw::{ActionRow, PreferencesGroup, PreferencesPage};
+
+use crate::arp_scan::{self, DiscoveredHost};
+
+/// LAN devices page
+pub struct DevicesPage {
+    widget: ScrolledWindow,
+}
+
+impl DevicesPage {
+    pub fn new() -> Self {
+        let page = PreferencesPage::new();
+        page.set_title("Dispositivos");
+        page.set_icon_name(Some("network-workgroup-symbolic"));
+
+        let scan_group = PreferencesGroup::builder()
+            .title("Descoberta de Dispositivos")
+            .description("Varredura ARP da rede local")
+            .build();
+
+        let scan_row = ActionRow::builder()
+            .title("Buscar Dispositivos")
+            .subtitle("Envia requisicoes ARP para a sub-rede da interface ativa")
+            .activatable(true)
+            .build();
+
+        let scan_spinner = Spinner::new();
+        scan_row.add_suffix(&scan_spinner);
+
+        let refresh_icon = Image::from_icon_name("view-refresh-symbolic");
+        scan_row.add_suffix(&refresh_icon);
+
+        scan_group.add(&scan_row);
+        page.add(&scan_group);
+
+        let devices_group = PreferencesGroup::builder()
+            .title("Dispositivos Encontrados")
+            .build();
+        page.add(&devices_group);
+
+        let scrolled = ScrolledWindow::builder()
+            .hscrollbar_policy(gtk4::PolicyType::Never)
+            .child(&page)
+            .build();
+
+        let device_rows: Rc<RefCell<Vec<ActionRow>>> = Rc::new(RefCell::new(Vec::new()));
+
+        let run_scan = {
+            let devices_group = devices_group.clone();
+            let device_rows = device_rows.clone();
+            let spinner = scan_spinner.clone();
+            Rc::new(move || {
+                let devices_group = devices_group.clone();
+                let device_rows = device_rows.clone();
+                let spinner = spinner.clone();
+
+                spinner.start();
+
+                let (tx, rx) = async_channel::bounded(1);
+                std::thread::spawn(move || {
+                    let result = match arp_scan::default_interface() {
+                        Some(interface) => {
+                            arp_scan::scan(&interface.name, arp_scan::default_scan_timeout())
+                        }
+                        None => Err(arp_scan::ArpError::InterfaceNotFound(
+                            "nenhuma interface ativa".to_string(),
+                        )),
+                    };
+                    let _ = tx.send_blocking(result);
+                });
+
+                glib::spawn_future_local(async move {
+                    if let Ok(result) = rx.recv().await {
+                        match result {
+                            Ok(hosts) => Self::rebuild(&devices_group, &device_rows, &hosts),
+                            Err(e) => tracing::error!("ARP scan failed: {}", e),
+                        }
+                    }
+                    spinner.stop();
+                });
+            })
+        };
+
+        scan_row.connect_activated({
+            let run_scan = run_scan.clone();
+            move |_| run_scan()
+        });
+
+        // Initial scan when the page is built
+        run_scan();
+
+        Self { widget: scrolled }
+    }
+
+    /// Replace the device list with the latest scan results
+    fn rebuild(
+        devices_group: &PreferencesGroup,
+        device_rows: &Rc<RefCell<Vec<ActionRow>>>,
+        hosts: &[DiscoveredHost],
+    ) {
+        for row in device_rows.borrow_mut().drain(..) {
+            devices_group.remove(&row);
+        }
+
+        if hosts.is_empty() {
+            let row = ActionRow::builder()
+                .title("Nenhum dispositivo encontrado")
+                .subtitle("Toque em \"Buscar Dispositivos\" para tentar novamente")
+                .build();
+            devices_group.add(&row);
+            device_rows.borrow_mut().push(row);
+            return;
+        }
+
+        for host in hosts {
+            let row = ActionRow::builder()
+                .title(&host.ip.to_string())
+                .subtitle(&format!("{} - {}", host.mac, host.vendor))
+                .build();
+
+            let icon = Image::from_icon_name("computer-symbolic");
+            row.add_prefix(&icon);
+
+            devices_group.add(&row);
+            device_rows.borrow_mut().push(row);
+        }
+    }
+
+    pub fn widget(&self) -> &ScrolledWindow {
+        &self.widget
+    }
+}
+
+impl Default for DevicesPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
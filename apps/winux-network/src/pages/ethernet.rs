@@ -6,11 +6,20 @@
 //! - MAC address
 //! - Speed and duplex info
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use gtk4::prelude::*;
 use gtk4::{Box, Button, Entry, Image, Label, Orientation, ScrolledWindow};
 use libadwaita as adw;
 use adw::prelude::*;
-use adw::{ActionRow, ComboRow, EntryRow, ExpanderRow, PreferencesGroup, PreferencesPage, SwitchRow};
+use adw::{
+    ActionRow, ComboRow, EntryRow, ExpanderRow, MessageDialog, PreferencesGroup, PreferencesPage,
+    ResponseAppearance, SwitchRow,
+};
+
+use crate::config::Config;
+use crate::wol::{self, WolHost};
 
 /// Ethernet page
 pub struct EthernetPage {
@@ -187,25 +196,65 @@ impl EthernetPage {
         // Wake on LAN
         let wol_group = PreferencesGroup::builder()
             .title("Wake on LAN")
-            .description("Ligar computador pela rede")
+            .description("Ligar computadores pela rede")
             .build();
 
+        let interface = Self::resolve_interface();
+
         let wol_switch = SwitchRow::builder()
             .title("Wake on LAN")
-            .subtitle("Permitir ligar pela rede")
-            .active(false)
+            .subtitle("Permitir que esta maquina seja ligada pela rede")
+            .active(wol::is_magic_packet_enabled(&interface).unwrap_or(false))
             .build();
+        wol_switch.connect_active_notify(move |row| {
+            if let Err(e) = wol::set_magic_packet_enabled(&interface, row.is_active()) {
+                tracing::warn!("Failed to set WoL flags on {interface}: {e}");
+                row.set_active(!row.is_active());
+            }
+        });
         wol_group.add(&wol_switch);
 
         let wol_magic = SwitchRow::builder()
             .title("Magic Packet")
-            .subtitle("Requer pacote magico para acordar")
+            .subtitle("Requer pacote magico para acordar (modo 'g')")
             .active(true)
             .build();
         wol_group.add(&wol_magic);
 
         page.add(&wol_group);
 
+        let config = Rc::new(RefCell::new(Config::load()));
+        let host_rows: Rc<RefCell<Vec<ActionRow>>> = Rc::new(RefCell::new(Vec::new()));
+        let hosts_group = PreferencesGroup::builder()
+            .title("Hosts Conhecidos")
+            .description("Computadores que podem ser acordados remotamente")
+            .build();
+
+        let add_row = ActionRow::builder().build();
+        let add_btn = Button::with_label("Adicionar Host");
+        add_btn.add_css_class("flat");
+        add_btn.set_halign(gtk4::Align::Start);
+        let hosts_group_clone = hosts_group.clone();
+        let host_rows_clone = host_rows.clone();
+        let config_clone = config.clone();
+        let add_row_clone = add_row.clone();
+        add_btn.connect_clicked(move |btn| {
+            if let Some(window) = btn.root().and_then(|r| r.downcast::<gtk4::Window>().ok()) {
+                Self::show_add_host_dialog(
+                    &window,
+                    hosts_group_clone.clone(),
+                    host_rows_clone.clone(),
+                    add_row_clone.clone(),
+                    config_clone.clone(),
+                );
+            }
+        });
+        add_row.set_child(Some(&add_btn));
+
+        Self::rebuild_host_rows(&hosts_group, &host_rows, &add_row, &config);
+
+        page.add(&hosts_group);
+
         let scrolled = ScrolledWindow::builder()
             .hscrollbar_policy(gtk4::PolicyType::Never)
             .child(&page)
@@ -217,6 +266,174 @@ impl EthernetPage {
     pub fn widget(&self) -> &ScrolledWindow {
         &self.widget
     }
+
+    /// Rebuild the list of known-host rows from `config`, keeping `add_row`
+    /// (the "Adicionar Host" button) last.
+    fn rebuild_host_rows(
+        group: &PreferencesGroup,
+        host_rows: &Rc<RefCell<Vec<ActionRow>>>,
+        add_row: &ActionRow,
+        config: &Rc<RefCell<Config>>,
+    ) {
+        for row in host_rows.borrow_mut().drain(..) {
+            group.remove(&row);
+        }
+        group.remove(add_row);
+
+        for host in config.borrow().wol_hosts.clone() {
+            let row = ActionRow::builder()
+                .title(&host.name)
+                .subtitle(&host.mac)
+                .build();
+
+            let wake_btn = Button::with_label("Acordar");
+            wake_btn.add_css_class("suggested-action");
+            wake_btn.set_valign(gtk4::Align::Center);
+            let host_clone = host.clone();
+            wake_btn.connect_clicked(move |btn| {
+                Self::wake_host(&host_clone, btn);
+            });
+            row.add_suffix(&wake_btn);
+
+            let remove_btn = Button::from_icon_name("user-trash-symbolic");
+            remove_btn.add_css_class("flat");
+            remove_btn.set_valign(gtk4::Align::Center);
+            remove_btn.set_tooltip_text(Some("Remover host"));
+            let group_clone = group.clone();
+            let host_rows_clone = host_rows.clone();
+            let add_row_clone = add_row.clone();
+            let config_clone = config.clone();
+            let mac = host.mac.clone();
+            remove_btn.connect_clicked(move |_| {
+                config_clone.borrow_mut().wol_hosts.retain(|h| h.mac != mac);
+                if let Err(e) = config_clone.borrow().save() {
+                    tracing::warn!("Failed to save network config: {e}");
+                }
+                Self::rebuild_host_rows(&group_clone, &host_rows_clone, &add_row_clone, &config_clone);
+            });
+            row.add_suffix(&remove_btn);
+
+            group.add(&row);
+            host_rows.borrow_mut().push(row);
+        }
+
+        group.add(add_row);
+    }
+
+    /// Resolve the interface this page manages at runtime — the first
+    /// active, non-loopback interface with an IPv4 address — rather than
+    /// assuming a fixed name, since that name varies across hardware.
+    /// Falls back to "enp3s0" if nothing matches so callers always have a
+    /// name to pass to `wol`.
+    fn resolve_interface() -> String {
+        crate::arp_scan::default_interface()
+            .map(|iface| iface.name)
+            .unwrap_or_else(|| {
+                tracing::warn!("No active network interface found, defaulting to enp3s0");
+                "enp3s0".to_string()
+            })
+    }
+
+    /// Send a magic packet to `host`, logging the result and showing an error dialog if sending fails.
+    fn wake_host(host: &WolHost, button: &Button) {
+        let interface = Self::resolve_interface();
+        let broadcast = wol::interface_broadcast_address(&interface).unwrap_or_else(|e| {
+            tracing::warn!(
+                "Failed to resolve broadcast address for {interface}, falling back to limited broadcast: {e}"
+            );
+            "255.255.255.255".to_string()
+        });
+
+        match wol::send_magic_packet(&host.mac, host.password.as_deref(), &broadcast) {
+            Ok(()) => {
+                tracing::info!("Sent WoL magic packet to {} ({})", host.name, host.mac);
+            }
+            Err(e) => {
+                tracing::error!("Failed to wake {}: {e}", host.name);
+                if let Some(window) = button.root().and_then(|r| r.downcast::<gtk4::Window>().ok()) {
+                    let dialog = MessageDialog::builder()
+                        .heading("Falha ao Enviar Magic Packet")
+                        .body(e.to_string())
+                        .transient_for(&window)
+                        .modal(true)
+                        .build();
+                    dialog.add_response("ok", "OK");
+                    dialog.present();
+                }
+            }
+        }
+    }
+
+    /// Show a dialog to add a new Wake-on-LAN host.
+    fn show_add_host_dialog(
+        parent: &gtk4::Window,
+        group: PreferencesGroup,
+        host_rows: Rc<RefCell<Vec<ActionRow>>>,
+        add_row: ActionRow,
+        config: Rc<RefCell<Config>>,
+    ) {
+        let dialog = MessageDialog::builder()
+            .heading("Adicionar Host")
+            .body("Informe o nome e o endereco MAC do computador a ser acordado")
+            .transient_for(parent)
+            .modal(true)
+            .build();
+
+        let content = Box::new(Orientation::Vertical, 8);
+        content.set_margin_start(24);
+        content.set_margin_end(24);
+
+        let name_entry = Entry::new();
+        name_entry.set_placeholder_text(Some("Nome (ex: Desktop do Escritorio)"));
+        content.append(&name_entry);
+
+        let mac_entry = Entry::new();
+        mac_entry.set_placeholder_text(Some("MAC (ex: 00:1A:2B:3C:4D:5E)"));
+        content.append(&mac_entry);
+
+        let password_entry = Entry::new();
+        password_entry.set_placeholder_text(Some("Senha SecureON (opcional)"));
+        content.append(&password_entry);
+
+        let error_label = Label::new(None);
+        error_label.add_css_class("error");
+        error_label.set_visible(false);
+        content.append(&error_label);
+
+        dialog.set_extra_child(Some(&content));
+
+        dialog.add_response("cancel", "Cancelar");
+        dialog.add_response("add", "Adicionar");
+        dialog.set_response_appearance("add", ResponseAppearance::Suggested);
+        dialog.set_default_response(Some("add"));
+        dialog.set_close_response("cancel");
+
+        dialog.connect_response(Some("add"), move |dialog, _| {
+            let name = name_entry.text().to_string();
+            let mac = mac_entry.text().to_string();
+            let password = password_entry.text().to_string();
+
+            if let Err(e) = wol::parse_mac(&mac) {
+                error_label.set_text(&e.to_string());
+                error_label.set_visible(true);
+                return;
+            }
+
+            config.borrow_mut().wol_hosts.push(WolHost {
+                name,
+                mac,
+                password: (!password.is_empty()).then_some(password),
+            });
+            if let Err(e) = config.borrow().save() {
+                tracing::warn!("Failed to save network config: {e}");
+            }
+
+            Self::rebuild_host_rows(&group, &host_rows, &add_row, &config);
+            dialog.close();
+        });
+
+        dialog.present();
+    }
 }
 
 impl Default for EthernetPage {
@@ -2,6 +2,7 @@
 
 mod wifi;
 mod ethernet;
+mod devices;
 mod vpn;
 mod hotspot;
 mod proxy;
@@ -9,6 +10,7 @@ mod advanced;
 
 pub use wifi::WifiPage;
 pub use ethernet::EthernetPage;
+pub use devices::DevicesPage;
 pub use vpn::VpnPage;
 pub use hotspot::HotspotPage;
 pub use proxy::ProxyPage;
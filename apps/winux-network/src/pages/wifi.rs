@@ -1,23 +1,27 @@
 //! WiFi networks page
 //!
 //! Features:
-//! - List available networks
-//! - Signal strength indicator
+//! - Live network scanning via NetworkManager
+//! - Signal strength indicator (dBm and quality %)
 //! - Connect/disconnect
 //! - Password configuration
 //! - Known networks management
 //! - Hidden network support
 
 use gtk4::prelude::*;
-use gtk4::{Box, Button, Entry, Image, Label, ListBox, Orientation, ScrolledWindow, Spinner};
+use gtk4::{Box, Button, Image, Label, Orientation, ScrolledWindow, Spinner};
 use libadwaita as adw;
 use adw::prelude::*;
 use adw::{ActionRow, ExpanderRow, PreferencesGroup, PreferencesPage, SwitchRow, EntryRow};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::nm::{security_display, AccessPoint, WifiScanner};
 use crate::ui::{NetworkRow, PasswordDialog};
 
+/// How often the background poller re-scans while the page is alive
+const SCAN_INTERVAL_SECS: u32 = 15;
+
 /// WiFi networks page
 pub struct WifiPage {
     widget: ScrolledWindow,
@@ -44,70 +48,10 @@ impl WifiPage {
 
         page.add(&toggle_group);
 
-        // Current connection group
+        // Current connection group, populated once a scan comes back
         let current_group = PreferencesGroup::builder()
             .title("Conexao Atual")
             .build();
-
-        let connected_row = ActionRow::builder()
-            .title("Casa_5G")
-            .subtitle("Conectado - Sinal excelente")
-            .build();
-
-        let signal_icon = Image::from_icon_name("network-wireless-signal-excellent-symbolic");
-        signal_icon.add_css_class("success");
-        connected_row.add_prefix(&signal_icon);
-
-        let secured_icon = Image::from_icon_name("network-wireless-encrypted-symbolic");
-        connected_row.add_suffix(&secured_icon);
-
-        let disconnect_btn = Button::with_label("Desconectar");
-        disconnect_btn.add_css_class("destructive-action");
-        disconnect_btn.set_valign(gtk4::Align::Center);
-        disconnect_btn.connect_clicked(|_| {
-            tracing::info!("Disconnecting from WiFi...");
-        });
-        connected_row.add_suffix(&disconnect_btn);
-
-        current_group.add(&connected_row);
-
-        // Connection details expander
-        let details_expander = ExpanderRow::builder()
-            .title("Detalhes da Conexao")
-            .subtitle("IP, MAC, velocidade")
-            .build();
-
-        let ip_row = ActionRow::builder()
-            .title("Endereco IP")
-            .subtitle("192.168.1.100")
-            .build();
-        details_expander.add_row(&ip_row);
-
-        let mac_row = ActionRow::builder()
-            .title("MAC Address")
-            .subtitle("AA:BB:CC:DD:EE:FF")
-            .build();
-        details_expander.add_row(&mac_row);
-
-        let speed_row = ActionRow::builder()
-            .title("Velocidade")
-            .subtitle("866 Mbps")
-            .build();
-        details_expander.add_row(&speed_row);
-
-        let freq_row = ActionRow::builder()
-            .title("Frequencia")
-            .subtitle("5 GHz")
-            .build();
-        details_expander.add_row(&freq_row);
-
-        let security_row = ActionRow::builder()
-            .title("Seguranca")
-            .subtitle("WPA2/WPA3")
-            .build();
-        details_expander.add_row(&security_row);
-
-        current_group.add(&details_expander);
         page.add(&current_group);
 
         // Available networks group
@@ -129,69 +73,7 @@ impl WifiPage {
         let refresh_icon = Image::from_icon_name("view-refresh-symbolic");
         scan_row.add_suffix(&refresh_icon);
 
-        scan_row.connect_activated({
-            let spinner = scan_spinner.clone();
-            move |_| {
-                spinner.start();
-                tracing::info!("Scanning for WiFi networks...");
-                // In real implementation, trigger NM scan here
-                glib::timeout_add_seconds_local_once(2, {
-                    let spinner = spinner.clone();
-                    move || spinner.stop()
-                });
-            }
-        });
-
         available_group.add(&scan_row);
-
-        // Sample available networks
-        let networks = [
-            ("Vizinho_Net", "network-wireless-signal-good-symbolic", true, 75),
-            ("Cafe_WiFi", "network-wireless-signal-ok-symbolic", false, 50),
-            ("Escritorio", "network-wireless-signal-excellent-symbolic", true, 95),
-            ("Guest_5G", "network-wireless-signal-weak-symbolic", true, 30),
-            ("OpenNet", "network-wireless-signal-ok-symbolic", false, 60),
-        ];
-
-        for (ssid, icon, secured, _signal) in networks {
-            let row = ActionRow::builder()
-                .title(ssid)
-                .activatable(true)
-                .build();
-
-            let signal_icon = Image::from_icon_name(icon);
-            row.add_prefix(&signal_icon);
-
-            if secured {
-                let lock_icon = Image::from_icon_name("network-wireless-encrypted-symbolic");
-                row.add_suffix(&lock_icon);
-            }
-
-            let connect_btn = Button::with_label("Conectar");
-            connect_btn.add_css_class("flat");
-            connect_btn.set_valign(gtk4::Align::Center);
-
-            let ssid_clone = ssid.to_string();
-            let secured_clone = secured;
-            connect_btn.connect_clicked(move |btn| {
-                if secured_clone {
-                    tracing::info!("Opening password dialog for {}", ssid_clone);
-                    // Show password dialog
-                    if let Some(window) = btn.root().and_then(|r| r.downcast::<gtk4::Window>().ok()) {
-                        PasswordDialog::show(&window, &ssid_clone, |password| {
-                            tracing::info!("Connecting to {} with password", ssid_clone);
-                            // Connect via NetworkManager
-                        });
-                    }
-                } else {
-                    tracing::info!("Connecting to open network {}", ssid_clone);
-                }
-            });
-            row.add_suffix(&connect_btn);
-
-            available_group.add(&row);
-        }
-
         page.add(&available_group);
 
         // Known networks group
@@ -286,9 +168,222 @@ impl WifiPage {
             .child(&page)
             .build();
 
+        // Live NetworkManager wiring. The scanner itself can only be built
+        // asynchronously (it opens a D-Bus connection), so it starts out
+        // empty and is filled in by the first scan.
+        let scanner: Rc<RefCell<Option<WifiScanner>>> = Rc::new(RefCell::new(None));
+        let current_rows: Rc<RefCell<Vec<gtk4::Widget>>> = Rc::new(RefCell::new(Vec::new()));
+        let available_rows: Rc<RefCell<Vec<gtk4::Widget>>> = Rc::new(RefCell::new(Vec::new()));
+
+        let apply_scan = {
+            let current_group = current_group.clone();
+            let available_group = available_group.clone();
+            let current_rows = current_rows.clone();
+            let available_rows = available_rows.clone();
+            let scanner = scanner.clone();
+            Rc::new(move |aps: Vec<AccessPoint>| {
+                Self::rebuild_current(&current_group, &current_rows, &aps, &scanner);
+                Self::rebuild_available(&available_group, &available_rows, &aps, &scanner);
+            })
+        };
+
+        let run_scan = {
+            let scanner = scanner.clone();
+            let apply_scan = apply_scan.clone();
+            let spinner = scan_spinner.clone();
+            Rc::new(move || {
+                let scanner = scanner.clone();
+                let apply_scan = apply_scan.clone();
+                let spinner = spinner.clone();
+                spinner.start();
+                glib::spawn_future_local(async move {
+                    let existing = scanner.borrow().clone();
+                    let client = match existing {
+                        Some(client) => Some(client),
+                        None => match WifiScanner::new().await {
+                            Ok(client) => {
+                                *scanner.borrow_mut() = Some(client.clone());
+                                Some(client)
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to reach NetworkManager: {}", e);
+                                None
+                            }
+                        },
+                    };
+
+                    if let Some(client) = client {
+                        match client.scan_once().await {
+                            Ok(aps) => apply_scan(aps),
+                            Err(e) => tracing::error!("WiFi scan failed: {}", e),
+                        }
+                    }
+
+                    spinner.stop();
+                });
+            })
+        };
+
+        // Initial scan on page load
+        run_scan();
+
+        // Manual rescan
+        scan_row.connect_activated({
+            let run_scan = run_scan.clone();
+            move |_| run_scan()
+        });
+
+        // Background poller so signal strength and the network list stay fresh
+        glib::timeout_add_seconds_local(SCAN_INTERVAL_SECS, move || {
+            run_scan();
+            glib::ControlFlow::Continue
+        });
+
         Self { widget: scrolled }
     }
 
+    /// Rebuild the "current connection" group from the latest scan
+    fn rebuild_current(
+        current_group: &PreferencesGroup,
+        current_rows: &Rc<RefCell<Vec<gtk4::Widget>>>,
+        aps: &[AccessPoint],
+        scanner: &Rc<RefCell<Option<WifiScanner>>>,
+    ) {
+        for widget in current_rows.borrow_mut().drain(..) {
+            current_group.remove(&widget);
+        }
+
+        match aps.iter().find(|ap| ap.is_connected) {
+            Some(ap) => {
+                let row = NetworkRow::from_access_point(ap, {
+                    let scanner = scanner.clone();
+                    move |btn| Self::disconnect(&scanner, btn)
+                });
+                current_group.add(row.widget());
+                current_rows.borrow_mut().push(row.widget().clone().upcast());
+
+                let details = ExpanderRow::builder()
+                    .title("Detalhes da Conexao")
+                    .subtitle("BSSID, canal, seguranca")
+                    .build();
+
+                let bssid_row = ActionRow::builder()
+                    .title("BSSID")
+                    .subtitle(&ap.bssid)
+                    .build();
+                details.add_row(&bssid_row);
+
+                let freq_row = ActionRow::builder()
+                    .title("Frequencia")
+                    .subtitle(&format!("{} MHz", ap.frequency))
+                    .build();
+                details.add_row(&freq_row);
+
+                let security_row = ActionRow::builder()
+                    .title("Seguranca")
+                    .subtitle(security_display(ap.security))
+                    .build();
+                details.add_row(&security_row);
+
+                current_group.add(&details);
+                current_rows.borrow_mut().push(details.clone().upcast());
+            }
+            None => {
+                let row = ActionRow::builder()
+                    .title("Nenhuma rede conectada")
+                    .subtitle("Selecione uma rede disponivel para conectar")
+                    .build();
+                let icon = Image::from_icon_name("network-wireless-offline-symbolic");
+                row.add_prefix(&icon);
+                current_group.add(&row);
+                current_rows.borrow_mut().push(row.upcast());
+            }
+        }
+    }
+
+    /// Rebuild the "available networks" group from the latest scan
+    fn rebuild_available(
+        available_group: &PreferencesGroup,
+        available_rows: &Rc<RefCell<Vec<gtk4::Widget>>>,
+        aps: &[AccessPoint],
+        scanner: &Rc<RefCell<Option<WifiScanner>>>,
+    ) {
+        for widget in available_rows.borrow_mut().drain(..) {
+            available_group.remove(&widget);
+        }
+
+        let mut sorted: Vec<&AccessPoint> = aps.iter().filter(|ap| !ap.is_connected).collect();
+        sorted.sort_by(|a, b| b.signal_strength.cmp(&a.signal_strength));
+
+        if sorted.is_empty() {
+            let row = ActionRow::builder()
+                .title("Nenhuma rede encontrada")
+                .subtitle("Toque em \"Buscar Redes\" para tentar novamente")
+                .build();
+            available_group.add(&row);
+            available_rows.borrow_mut().push(row.upcast());
+            return;
+        }
+
+        for ap in sorted {
+            let ap_owned = ap.clone();
+            let row = NetworkRow::from_access_point(ap, {
+                let scanner = scanner.clone();
+                move |btn| Self::connect(&scanner, &ap_owned, btn)
+            });
+            available_group.add(row.widget());
+            available_rows.borrow_mut().push(row.widget().clone().upcast());
+        }
+    }
+
+    /// Connect to an access point, prompting for a password if it's secured
+    fn connect(scanner: &Rc<RefCell<Option<WifiScanner>>>, ap: &AccessPoint, btn: &Button) {
+        use crate::nm::WifiSecurity;
+
+        let Some(window) = btn.root().and_then(|r| r.downcast::<gtk4::Window>().ok()) else {
+            return;
+        };
+        let scanner = scanner.clone();
+        let ssid = ap.ssid.clone();
+
+        if ap.security == WifiSecurity::None {
+            glib::spawn_future_local(async move {
+                if let Some(client) = scanner.borrow().clone() {
+                    if let Err(e) = client.connect(&ssid, None).await {
+                        tracing::error!("Failed to connect to {}: {}", ssid, e);
+                        PasswordDialog::show_error(&window, "Falha na Conexao", &e.to_string());
+                    }
+                }
+            });
+        } else {
+            PasswordDialog::show(&window, &ssid, move |password| {
+                let scanner = scanner.clone();
+                let ssid = ssid.clone();
+                let window = window.clone();
+                glib::spawn_future_local(async move {
+                    if let Some(client) = scanner.borrow().clone() {
+                        if let Err(e) = client.connect(&ssid, Some(&password)).await {
+                            tracing::error!("Failed to connect to {}: {}", ssid, e);
+                            PasswordDialog::show_error(&window, "Falha na Conexao", &e.to_string());
+                        }
+                    }
+                });
+            });
+        }
+    }
+
+    /// Disconnect from the currently active WiFi network
+    fn disconnect(scanner: &Rc<RefCell<Option<WifiScanner>>>, _btn: &Button) {
+        let scanner = scanner.clone();
+        glib::spawn_future_local(async move {
+            if let Some(client) = scanner.borrow().clone() {
+                if let Err(e) = client.disconnect().await {
+                    tracing::error!("Failed to disconnect WiFi: {}", e);
+                }
+            }
+        });
+    }
+
     pub fn widget(&self) -> &ScrolledWindow {
         &self.widget
     }
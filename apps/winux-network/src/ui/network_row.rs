@@ -8,7 +8,7 @@ use libadwaita as adw;
 use adw::prelude::*;
 use adw::ActionRow;
 
-use crate::nm::{AccessPoint, WifiSecurity, signal_strength_icon, security_display};
+use crate::nm::{AccessPoint, WifiSecurity, quality_to_dbm, signal_strength_icon, security_display};
 
 /// Network row widget
 pub struct NetworkRow {
@@ -17,7 +17,16 @@ pub struct NetworkRow {
 
 impl NetworkRow {
     /// Create a new network row from an access point
-    pub fn from_access_point(ap: &AccessPoint) -> Self {
+    ///
+    /// `on_action` is invoked with the clicked button when the row's
+    /// action is used: connect for networks that aren't active,
+    /// disconnect for the one that is. The button is passed through so
+    /// callers can find a transient-for window (e.g. for a password
+    /// dialog) via `button.root()`.
+    pub fn from_access_point<F>(ap: &AccessPoint, on_action: F) -> Self
+    where
+        F: Fn(&Button) + 'static,
+    {
         let row = ActionRow::builder()
             .title(&ap.ssid)
             .activatable(true)
@@ -37,20 +46,32 @@ impl NetworkRow {
             row.add_suffix(&security_icon);
         }
 
+        let dbm = quality_to_dbm(ap.signal_strength);
+
         // Connection status or connect button
         if ap.is_connected {
-            row.set_subtitle("Conectado");
+            row.set_subtitle(&format!("Conectado - {}% ({} dBm)", ap.signal_strength, dbm));
 
             let check_icon = Image::from_icon_name("emblem-ok-symbolic");
             check_icon.add_css_class("success");
             row.add_suffix(&check_icon);
+
+            let disconnect_btn = Button::with_label("Desconectar");
+            disconnect_btn.add_css_class("destructive-action");
+            disconnect_btn.set_valign(gtk4::Align::Center);
+            disconnect_btn.connect_clicked(move |btn| on_action(btn));
+            row.add_suffix(&disconnect_btn);
         } else {
             let freq_band = if ap.frequency > 5000 { "5 GHz" } else { "2.4 GHz" };
-            row.set_subtitle(&format!("{} - {}%", freq_band, ap.signal_strength));
+            row.set_subtitle(&format!(
+                "{} - {}% ({} dBm)",
+                freq_band, ap.signal_strength, dbm
+            ));
 
             let connect_btn = Button::with_label("Conectar");
             connect_btn.add_css_class("flat");
             connect_btn.set_valign(gtk4::Align::Center);
+            connect_btn.connect_clicked(move |btn| on_action(btn));
             row.add_suffix(&connect_btn);
         }
 
@@ -197,10 +218,13 @@ impl NetworkRow {
 }
 
 /// Helper to create a list of network rows from access points
+///
+/// Rows are created without a connect/disconnect action; use
+/// [`NetworkRow::from_access_point`] directly when actions are needed.
 pub fn create_network_list(access_points: &[AccessPoint]) -> Vec<NetworkRow> {
     access_points
         .iter()
-        .map(NetworkRow::from_access_point)
+        .map(|ap| NetworkRow::from_access_point(ap, |_| {}))
         .collect()
 }
 
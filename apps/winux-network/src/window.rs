@@ -29,6 +29,11 @@ impl NetworkWindow {
         stack.add_titled(ethernet_page.widget(), Some("ethernet"), "Ethernet")
             .set_icon_name(Some("network-wired-symbolic"));
 
+        // Devices Page
+        let devices_page = pages::DevicesPage::new();
+        stack.add_titled(devices_page.widget(), Some("devices"), "Dispositivos")
+            .set_icon_name(Some("network-workgroup-symbolic"));
+
         // VPN Page
         let vpn_page = pages::VpnPage::new();
         stack.add_titled(vpn_page.widget(), Some("vpn"), "VPN")
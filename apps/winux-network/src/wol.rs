@@ -0,0 +1,183 @@
+//! Wake-on-LAN subsystem
+//!
+//! Builds and sends WoL "magic packets" to known hosts, and reads/writes the
+//! Wake-on-LAN flags of a local interface via `ethtool`.
+
+use std::net::UdpSocket;
+use std::process::Command;
+
+use ipnetwork::IpNetwork;
+use pnet::datalink;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A host that can be woken over the network
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WolHost {
+    pub name: String,
+    pub mac: String,
+    /// Optional SecureON password, stored as colon/hyphen separated hex
+    pub password: Option<String>,
+}
+
+/// Wake-on-LAN errors
+#[derive(Error, Debug)]
+pub enum WolError {
+    #[error("invalid MAC address: {0}")]
+    InvalidMac(String),
+
+    #[error("invalid SecureON password: {0}")]
+    InvalidPassword(String),
+
+    #[error("invalid IPv4 address: {0}")]
+    InvalidAddress(String),
+
+    #[error("network interface not found or has no IPv4 address: {0}")]
+    InterfaceNotFound(String),
+
+    #[error("I/O error sending magic packet: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("ethtool failed: {0}")]
+    Ethtool(String),
+}
+
+/// The standard ports magic packets are sent to, tried in order
+const WOL_PORTS: [u16; 2] = [9, 7];
+
+/// Parse a MAC address from colon- or hyphen-separated hex (e.g. `00:1A:2B:3C:4D:5E`)
+pub fn parse_mac(mac: &str) -> Result<[u8; 6], WolError> {
+    let bytes: Vec<u8> = mac
+        .split(|c| c == ':' || c == '-')
+        .map(|part| u8::from_str_radix(part, 16).map_err(|_| WolError::InvalidMac(mac.to_string())))
+        .collect::<Result<_, _>>()?;
+
+    bytes
+        .try_into()
+        .map_err(|_| WolError::InvalidMac(mac.to_string()))
+}
+
+/// Build a 102-byte magic packet (6 bytes of `0xFF` + MAC repeated 16 times),
+/// optionally followed by a 6-byte SecureON password.
+pub fn build_magic_packet(mac: [u8; 6], password: Option<[u8; 6]>) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(102 + 6);
+    packet.extend_from_slice(&[0xFF; 6]);
+    for _ in 0..16 {
+        packet.extend_from_slice(&mac);
+    }
+    if let Some(password) = password {
+        packet.extend_from_slice(&password);
+    }
+    packet
+}
+
+/// Send a magic packet to `broadcast_addr`, trying port 9 then falling back to port 7.
+pub fn send_magic_packet(
+    mac: &str,
+    password: Option<&str>,
+    broadcast_addr: &str,
+) -> Result<(), WolError> {
+    let mac = parse_mac(mac)?;
+    let password = password
+        .map(parse_mac)
+        .transpose()
+        .map_err(|_| WolError::InvalidPassword(password.unwrap_or_default().to_string()))?;
+
+    let packet = build_magic_packet(mac, password);
+
+    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    socket.set_broadcast(true)?;
+
+    let mut last_err = None;
+    for port in WOL_PORTS {
+        match socket.send_to(&packet, (broadcast_addr, port)) {
+            Ok(_) => {
+                tracing::info!("Sent WoL magic packet to {broadcast_addr}:{port}");
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::warn!("Failed to send WoL packet to {broadcast_addr}:{port}: {e}");
+                last_err = Some(e);
+            }
+        }
+    }
+
+    Err(last_err.expect("WOL_PORTS is non-empty").into())
+}
+
+/// Read whether magic-packet Wake-on-LAN (`g` mode) is enabled on `interface`.
+pub fn is_magic_packet_enabled(interface: &str) -> Result<bool, WolError> {
+    let output = Command::new("ethtool")
+        .arg(interface)
+        .output()
+        .map_err(|e| WolError::Ethtool(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(WolError::Ethtool(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Wake-on:"))
+        .is_some_and(|modes| modes.trim().contains('g')))
+}
+
+/// Compute the subnet broadcast address from an IPv4 address and netmask.
+pub fn broadcast_address(ip: &str, netmask: &str) -> Result<String, WolError> {
+    let parse = |s: &str| -> Result<[u8; 4], WolError> {
+        let octets: Vec<u8> = s
+            .split('.')
+            .map(|p| p.parse::<u8>().map_err(|_| WolError::InvalidAddress(s.to_string())))
+            .collect::<Result<_, _>>()?;
+        octets.try_into().map_err(|_| WolError::InvalidAddress(s.to_string()))
+    };
+
+    let ip = parse(ip)?;
+    let mask = parse(netmask)?;
+
+    let broadcast: Vec<String> = ip
+        .iter()
+        .zip(mask.iter())
+        .map(|(i, m)| (i | !m).to_string())
+        .collect();
+
+    Ok(broadcast.join("."))
+}
+
+/// Compute the subnet broadcast address of `interface`'s actual IPv4
+/// address/netmask, so a magic packet is sent as a directed broadcast on
+/// whatever subnet the machine is really on rather than a hardcoded one.
+pub fn interface_broadcast_address(interface: &str) -> Result<String, WolError> {
+    let network = datalink::interfaces()
+        .into_iter()
+        .find(|iface| iface.name == interface)
+        .and_then(|iface| {
+            iface.ips.into_iter().find_map(|ip| match ip {
+                IpNetwork::V4(net) => Some(net),
+                IpNetwork::V6(_) => None,
+            })
+        })
+        .ok_or_else(|| WolError::InterfaceNotFound(interface.to_string()))?;
+
+    broadcast_address(&network.ip().to_string(), &network.mask().to_string())
+}
+
+/// Enable or disable magic-packet Wake-on-LAN on `interface` via `ethtool -s <iface> wol g|d`.
+pub fn set_magic_packet_enabled(interface: &str, enabled: bool) -> Result<(), WolError> {
+    let mode = if enabled { "g" } else { "d" };
+    let output = Command::new("ethtool")
+        .args(["-s", interface, "wol", mode])
+        .output()
+        .map_err(|e| WolError::Ethtool(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(WolError::Ethtool(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(())
+}
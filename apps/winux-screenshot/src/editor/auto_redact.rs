@@ -0,0 +1,399 @@
+//! OCR-driven automatic detection of sensitive regions, so a screenshot can
+//! be scrubbed without the user manually drawing blur rectangles
+
+use std::process::Command;
+
+use anyhow::{anyhow, Context, Result};
+use image::DynamicImage;
+use regex::Regex;
+
+use super::blur::{BlurEffect, RedactionMode};
+
+/// Category of sensitive text a [`DetectedRegion`] was matched against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SensitiveCategory {
+    Email,
+    CreditCard,
+    IpAddress,
+    ApiKey,
+    PhoneNumber,
+}
+
+impl SensitiveCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Email => "Email",
+            Self::CreditCard => "Credit card",
+            Self::IpAddress => "IP address",
+            Self::ApiKey => "API key",
+            Self::PhoneNumber => "Phone number",
+        }
+    }
+}
+
+/// A region of an image flagged as containing sensitive text, pending user
+/// confirmation before it's actually redacted
+#[derive(Debug, Clone)]
+pub struct DetectedRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub category: SensitiveCategory,
+    pub text: String,
+}
+
+/// A single OCR word box as reported by tesseract's `tsv` output
+#[derive(Debug, Clone)]
+struct WordBox {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    text: String,
+}
+
+/// A regex-based sensitive-data pattern checked against a single OCR word
+#[derive(Debug, Clone)]
+pub struct RedactPattern {
+    pub category: SensitiveCategory,
+    pub pattern: Regex,
+}
+
+impl RedactPattern {
+    pub fn new(category: SensitiveCategory, pattern: Regex) -> Self {
+        Self { category, pattern }
+    }
+}
+
+/// Detects sensitive text in screenshots via OCR, turning the manual
+/// region-redaction primitives in [`super::blur`] into a one-click
+/// "scrub my screenshot" capability
+///
+/// Credit cards are always checked via the Luhn checksum rather than a plain
+/// pattern (see [`is_credit_card`]); `patterns` covers everything else and
+/// can be customized via [`AutoRedactor::with_patterns`] to add or drop
+/// sensitive-data formats without touching this module.
+pub struct AutoRedactor {
+    patterns: Vec<RedactPattern>,
+}
+
+impl AutoRedactor {
+    /// Create a redactor using the built-in patterns (email, IP address,
+    /// API key, phone number)
+    pub fn new() -> Self {
+        Self {
+            patterns: default_patterns(),
+        }
+    }
+
+    /// Create a redactor with a custom pattern set, e.g. to add
+    /// organization-specific sensitive-data formats
+    pub fn with_patterns(patterns: Vec<RedactPattern>) -> Self {
+        Self { patterns }
+    }
+
+    /// Run OCR over `image` and return every region whose recognized text
+    /// matches one of this redactor's sensitive-data patterns.
+    ///
+    /// Credit card numbers are detected both as single OCR tokens and as
+    /// runs of adjacent digit-only boxes on the same line joined together,
+    /// so a card number rendered with spaces (split across several boxes by
+    /// OCR) is still caught as a whole.
+    pub fn detect(&self, image: &DynamicImage) -> Result<Vec<DetectedRegion>> {
+        let words = Self::ocr_words(image)?;
+        let mut consumed = vec![false; words.len()];
+        let mut regions = Vec::new();
+
+        for line in Self::line_groups(&words) {
+            regions.extend(Self::detect_merged_credit_cards(
+                &words,
+                &line,
+                &mut consumed,
+            ));
+        }
+
+        for (index, word) in words.iter().enumerate() {
+            if consumed[index] {
+                continue;
+            }
+            if let Some(category) = self.classify(&word.text) {
+                regions.push(DetectedRegion {
+                    x: word.x,
+                    y: word.y,
+                    width: word.width,
+                    height: word.height,
+                    category,
+                    text: word.text.clone(),
+                });
+            }
+        }
+
+        Ok(regions)
+    }
+
+    /// Redact every region in `regions` on top of `image` using `mode`
+    pub fn redact_all(
+        image: &DynamicImage,
+        regions: &[DetectedRegion],
+        mode: RedactionMode,
+    ) -> DynamicImage {
+        let mut result = image.clone();
+        for region in regions {
+            result = BlurEffect::redact(&result, region.x, region.y, region.width, region.height, mode);
+        }
+        result
+    }
+
+    /// Run `tesseract` over `image`, returning its recognized word boxes
+    fn ocr_words(image: &DynamicImage) -> Result<Vec<WordBox>> {
+        let input = tempfile::Builder::new()
+            .suffix(".png")
+            .tempfile()
+            .context("failed to create temp file for OCR input")?;
+
+        image
+            .save(input.path())
+            .context("failed to write image for OCR")?;
+
+        let output = Command::new("tesseract")
+            .arg(input.path())
+            .arg("stdout")
+            .arg("--psm")
+            .arg("11")
+            .arg("tsv")
+            .output()
+            .context("failed to run tesseract (is it installed?)")?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "tesseract failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(Self::parse_tsv(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Parse tesseract's `tsv` output into word-level bounding boxes,
+    /// skipping the header row and any row without recognized text
+    fn parse_tsv(tsv: &str) -> Vec<WordBox> {
+        let mut words = Vec::new();
+
+        for line in tsv.lines().skip(1) {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 12 {
+                continue;
+            }
+
+            let text = fields[11].trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let (Ok(x), Ok(y), Ok(width), Ok(height)) = (
+                fields[6].parse::<u32>(),
+                fields[7].parse::<u32>(),
+                fields[8].parse::<u32>(),
+                fields[9].parse::<u32>(),
+            ) else {
+                continue;
+            };
+
+            words.push(WordBox {
+                x,
+                y,
+                width,
+                height,
+                text: text.to_string(),
+            });
+        }
+
+        words
+    }
+
+    /// Classify a single OCR word against this redactor's patterns, in
+    /// priority order, checking the credit card Luhn validation first since
+    /// it isn't a plain pattern.
+    fn classify(&self, text: &str) -> Option<SensitiveCategory> {
+        if is_credit_card(text) {
+            return Some(SensitiveCategory::CreditCard);
+        }
+
+        self.patterns
+            .iter()
+            .find(|candidate| candidate.pattern.is_match(text))
+            .map(|candidate| candidate.category)
+    }
+
+    /// Group word box indices into text lines by vertical overlap, each line
+    /// sorted left to right, so adjacent boxes on a line can be merged.
+    fn line_groups(words: &[WordBox]) -> Vec<Vec<usize>> {
+        let mut indices: Vec<usize> = (0..words.len()).collect();
+        indices.sort_by_key(|&i| (words[i].y, words[i].x));
+
+        let mut lines: Vec<Vec<usize>> = Vec::new();
+        for index in indices {
+            let word = &words[index];
+            let word_center = word.y as i64 + word.height as i64 / 2;
+
+            let line = lines.iter_mut().find(|line| {
+                let last = &words[*line.last().expect("line is never empty")];
+                let last_center = last.y as i64 + last.height as i64 / 2;
+                (word_center - last_center).abs()
+                    <= (word.height.min(last.height) as i64 / 2).max(1)
+            });
+
+            match line {
+                Some(line) => line.push(index),
+                None => lines.push(vec![index]),
+            }
+        }
+
+        for line in &mut lines {
+            line.sort_by_key(|&i| words[i].x);
+        }
+
+        lines
+    }
+
+    /// Scan a line's word boxes for the longest run of adjacent digit-only
+    /// boxes whose concatenated text passes the credit card Luhn check,
+    /// marking every box it consumes in `consumed` so it isn't also matched
+    /// individually.
+    fn detect_merged_credit_cards(
+        words: &[WordBox],
+        line: &[usize],
+        consumed: &mut [bool],
+    ) -> Vec<DetectedRegion> {
+        let mut regions = Vec::new();
+        let mut start = 0;
+
+        while start < line.len() {
+            let mut best: Option<(usize, String)> = None;
+
+            for end in (start + 1)..=line.len() {
+                let candidates = &line[start..end];
+                let all_digits = candidates.iter().all(|&index| {
+                    !words[index].text.is_empty()
+                        && words[index].text.chars().all(|c| c.is_ascii_digit())
+                });
+                if !all_digits {
+                    break;
+                }
+
+                let joined: String = candidates
+                    .iter()
+                    .map(|&index| words[index].text.as_str())
+                    .collect();
+                if is_credit_card(&joined) {
+                    best = Some((end, joined));
+                }
+            }
+
+            match best {
+                Some((end, joined)) => {
+                    let boxes = line[start..end].iter().map(|&index| &words[index]);
+                    let (x, y, width, height) = Self::bounding_box(boxes);
+                    regions.push(DetectedRegion {
+                        x,
+                        y,
+                        width,
+                        height,
+                        category: SensitiveCategory::CreditCard,
+                        text: joined,
+                    });
+                    for &index in &line[start..end] {
+                        consumed[index] = true;
+                    }
+                    start = end;
+                }
+                None => start += 1,
+            }
+        }
+
+        regions
+    }
+
+    /// The bounding box enclosing every word box in `boxes`
+    fn bounding_box<'a>(boxes: impl Iterator<Item = &'a WordBox>) -> (u32, u32, u32, u32) {
+        let mut min_x = u32::MAX;
+        let mut min_y = u32::MAX;
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for word in boxes {
+            min_x = min_x.min(word.x);
+            min_y = min_y.min(word.y);
+            max_x = max_x.max(word.x + word.width);
+            max_y = max_y.max(word.y + word.height);
+        }
+
+        (min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+}
+
+impl Default for AutoRedactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The built-in sensitive-data patterns used by [`AutoRedactor::new`]
+fn default_patterns() -> Vec<RedactPattern> {
+    vec![
+        RedactPattern::new(SensitiveCategory::Email, email_pattern()),
+        RedactPattern::new(SensitiveCategory::IpAddress, ipv4_pattern()),
+        RedactPattern::new(SensitiveCategory::IpAddress, ipv6_pattern()),
+        RedactPattern::new(SensitiveCategory::ApiKey, api_key_pattern()),
+        RedactPattern::new(SensitiveCategory::PhoneNumber, phone_pattern()),
+    ]
+}
+
+fn email_pattern() -> Regex {
+    Regex::new(r"^[\w.+-]+@[\w-]+\.[\w.-]+$").unwrap()
+}
+
+fn ipv4_pattern() -> Regex {
+    Regex::new(r"^(\d{1,3}\.){3}\d{1,3}$").unwrap()
+}
+
+fn ipv6_pattern() -> Regex {
+    Regex::new(r"^[0-9a-fA-F]{1,4}(:[0-9a-fA-F]{0,4}){2,7}$").unwrap()
+}
+
+fn api_key_pattern() -> Regex {
+    Regex::new(r"^[A-Za-z0-9_-]{20,}$").unwrap()
+}
+
+fn phone_pattern() -> Regex {
+    Regex::new(r"^\+?\d[\d().-]{7,14}\d$").unwrap()
+}
+
+/// Validate a credit-card-shaped digit run via the Luhn checksum
+fn is_credit_card(text: &str) -> bool {
+    if !text.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let digits: Vec<u32> = text.chars().map(|c| c.to_digit(10).unwrap()).collect();
+    matches!(digits.len(), 13..=19) && luhn_checksum(&digits)
+}
+
+fn luhn_checksum(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum();
+
+    sum % 10 == 0
+}
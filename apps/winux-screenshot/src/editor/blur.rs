@@ -53,78 +53,16 @@ impl BlurEffect {
         result
     }
 
-    /// Simple box blur implementation
+    /// Box blur implementation using a constant-time sliding window sum, so
+    /// cost is independent of `radius` (see [`sliding_box_blur`])
     fn box_blur(image: &DynamicImage, radius: u32) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
         let rgba = image.to_rgba8();
-        let (width, height) = rgba.dimensions();
 
-        if radius == 0 || width == 0 || height == 0 {
+        if radius == 0 || rgba.width() == 0 || rgba.height() == 0 {
             return rgba;
         }
 
-        let radius = radius as i32;
-        let kernel_size = (radius * 2 + 1) as f32;
-
-        let mut result = ImageBuffer::new(width, height);
-
-        // Horizontal pass
-        let mut temp = ImageBuffer::new(width, height);
-        for y in 0..height {
-            for x in 0..width {
-                let mut r_sum = 0u32;
-                let mut g_sum = 0u32;
-                let mut b_sum = 0u32;
-                let mut a_sum = 0u32;
-                let mut count = 0u32;
-
-                for kx in -radius..=radius {
-                    let src_x = (x as i32 + kx).clamp(0, width as i32 - 1) as u32;
-                    let pixel = rgba.get_pixel(src_x, y);
-                    r_sum += pixel[0] as u32;
-                    g_sum += pixel[1] as u32;
-                    b_sum += pixel[2] as u32;
-                    a_sum += pixel[3] as u32;
-                    count += 1;
-                }
-
-                temp.put_pixel(x, y, Rgba([
-                    (r_sum / count) as u8,
-                    (g_sum / count) as u8,
-                    (b_sum / count) as u8,
-                    (a_sum / count) as u8,
-                ]));
-            }
-        }
-
-        // Vertical pass
-        for y in 0..height {
-            for x in 0..width {
-                let mut r_sum = 0u32;
-                let mut g_sum = 0u32;
-                let mut b_sum = 0u32;
-                let mut a_sum = 0u32;
-                let mut count = 0u32;
-
-                for ky in -radius..=radius {
-                    let src_y = (y as i32 + ky).clamp(0, height as i32 - 1) as u32;
-                    let pixel = temp.get_pixel(x, src_y);
-                    r_sum += pixel[0] as u32;
-                    g_sum += pixel[1] as u32;
-                    b_sum += pixel[2] as u32;
-                    a_sum += pixel[3] as u32;
-                    count += 1;
-                }
-
-                result.put_pixel(x, y, Rgba([
-                    (r_sum / count) as u8,
-                    (g_sum / count) as u8,
-                    (b_sum / count) as u8,
-                    (a_sum / count) as u8,
-                ]));
-            }
-        }
-
-        result
+        sliding_box_blur(&rgba, radius as i32)
     }
 
     /// Apply pixelation effect to a region
@@ -209,6 +147,214 @@ impl BlurEffect {
 
         result
     }
+
+    /// Redact a region of `image` using `mode`, overwriting the backing
+    /// pixels directly rather than compositing an approximation over the
+    /// original content. Prefer [`RedactionMode::SolidFill`] or
+    /// [`RedactionMode::Noise`] for genuinely sensitive regions - see
+    /// [`RedactionMode::is_reversible`].
+    pub fn redact(
+        image: &DynamicImage,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        mode: RedactionMode,
+    ) -> DynamicImage {
+        let image = &DynamicImage::ImageRgba8(image.to_rgba8());
+        match mode {
+            RedactionMode::SolidFill(color) => Self::fill_solid(image, x, y, width, height, color),
+            RedactionMode::Noise => Self::fill_noise(image, x, y, width, height),
+            RedactionMode::Blur(strength) => Self::apply(image, x, y, width, height, strength),
+            RedactionMode::Pixelate(block_size) => {
+                Self::pixelate(image, x, y, width, height, block_size)
+            }
+        }
+    }
+
+    /// Paint a region with a single opaque color, destroying all signal
+    fn fill_solid(
+        image: &DynamicImage,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        color: Rgba<u8>,
+    ) -> DynamicImage {
+        let mut result = image.clone();
+
+        let img_width = image.width();
+        let img_height = image.height();
+
+        let x = x.min(img_width);
+        let y = y.min(img_height);
+        let width = width.min(img_width - x);
+        let height = height.min(img_height - y);
+
+        if width == 0 || height == 0 {
+            return result;
+        }
+
+        if let Some(rgba) = result.as_mut_rgba8() {
+            for py in y..y + height {
+                for px in x..x + width {
+                    rgba.put_pixel(px, py, color);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Fill a region with cryptographically-seeded random RGBA, destroying
+    /// all recoverable signal. Alpha is forced fully opaque so no trace of
+    /// the original content shows through in the saved image.
+    fn fill_noise(image: &DynamicImage, x: u32, y: u32, width: u32, height: u32) -> DynamicImage {
+        use rand::RngCore;
+
+        let mut result = image.clone();
+
+        let img_width = image.width();
+        let img_height = image.height();
+
+        let x = x.min(img_width);
+        let y = y.min(img_height);
+        let width = width.min(img_width - x);
+        let height = height.min(img_height - y);
+
+        if width == 0 || height == 0 {
+            return result;
+        }
+
+        let mut rng = rand::thread_rng();
+
+        if let Some(rgba) = result.as_mut_rgba8() {
+            for py in y..y + height {
+                for px in x..x + width {
+                    let mut pixel = [0u8; 4];
+                    rng.fill_bytes(&mut pixel);
+                    pixel[3] = 255;
+                    rgba.put_pixel(px, py, Rgba(pixel));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// How thoroughly a [`BlurEffect::redact`] mode hides the content underneath it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RedactionMode {
+    /// Paint the region with a single opaque color - irreversible
+    SolidFill(Rgba<u8>),
+    /// Fill each pixel with cryptographically-seeded random RGBA - irreversible
+    Noise,
+    /// Box blur of the given strength - averaging-based, partially reversible
+    Blur(u32),
+    /// Pixelate/mosaic with the given block size - averaging-based, partially reversible
+    Pixelate(u32),
+}
+
+impl RedactionMode {
+    /// Whether content hidden with this mode could potentially be recovered.
+    /// `Blur` and `Pixelate` only average the underlying signal, which
+    /// deblurring/depixelation models can partially invert; `SolidFill` and
+    /// `Noise` destroy it outright.
+    pub fn is_reversible(&self) -> bool {
+        matches!(self, RedactionMode::Blur(_) | RedactionMode::Pixelate(_))
+    }
+}
+
+/// Box-blur `rgba` with the given `radius` in O(width*height) time, independent
+/// of `radius`, by sliding a running r/g/b/a sum across each row and then each
+/// column instead of re-summing the whole kernel window per output pixel.
+/// Edges are handled by clamping the window to the nearest valid pixel, which
+/// matches a naive per-pixel box blur exactly.
+fn sliding_box_blur(
+    rgba: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    radius: i32,
+) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    let (width, height) = rgba.dimensions();
+    if radius <= 0 || width == 0 || height == 0 {
+        return rgba.clone();
+    }
+
+    let count = (radius * 2 + 1) as u32;
+
+    // Horizontal pass
+    let mut temp = ImageBuffer::new(width, height);
+    for y in 0..height {
+        let clamp_x = |x: i32| x.clamp(0, width as i32 - 1) as u32;
+
+        let mut sum = [0u32; 4];
+        for kx in -radius..=radius {
+            let pixel = rgba.get_pixel(clamp_x(kx), y);
+            for c in 0..4 {
+                sum[c] += pixel[c] as u32;
+            }
+        }
+
+        for x in 0..width {
+            temp.put_pixel(
+                x,
+                y,
+                Rgba([
+                    (sum[0] / count) as u8,
+                    (sum[1] / count) as u8,
+                    (sum[2] / count) as u8,
+                    (sum[3] / count) as u8,
+                ]),
+            );
+
+            if x + 1 < width {
+                let outgoing = rgba.get_pixel(clamp_x(x as i32 - radius), y);
+                let incoming = rgba.get_pixel(clamp_x(x as i32 + 1 + radius), y);
+                for c in 0..4 {
+                    sum[c] += incoming[c] as u32;
+                    sum[c] -= outgoing[c] as u32;
+                }
+            }
+        }
+    }
+
+    // Vertical pass
+    let mut result = ImageBuffer::new(width, height);
+    for x in 0..width {
+        let clamp_y = |y: i32| y.clamp(0, height as i32 - 1) as u32;
+
+        let mut sum = [0u32; 4];
+        for ky in -radius..=radius {
+            let pixel = temp.get_pixel(x, clamp_y(ky));
+            for c in 0..4 {
+                sum[c] += pixel[c] as u32;
+            }
+        }
+
+        for y in 0..height {
+            result.put_pixel(
+                x,
+                y,
+                Rgba([
+                    (sum[0] / count) as u8,
+                    (sum[1] / count) as u8,
+                    (sum[2] / count) as u8,
+                    (sum[3] / count) as u8,
+                ]),
+            );
+
+            if y + 1 < height {
+                let outgoing = temp.get_pixel(x, clamp_y(y as i32 - radius));
+                let incoming = temp.get_pixel(x, clamp_y(y as i32 + 1 + radius));
+                for c in 0..4 {
+                    sum[c] += incoming[c] as u32;
+                    sum[c] -= outgoing[c] as u32;
+                }
+            }
+        }
+    }
+
+    result
 }
 
 /// Gaussian blur for higher quality (slower)
@@ -338,4 +484,88 @@ impl GaussianBlur {
 
         result
     }
+
+    /// Compute the three box-blur radii that approximate a Gaussian of `sigma`,
+    /// per the standard "three-pass box blur" construction: an ideal box width
+    /// `w` is derived from `sigma`, then `m` passes use the largest odd integer
+    /// `wl <= w` and the remaining `3 - m` passes use `wl + 2`.
+    fn box_radii(sigma: f32) -> [i32; 3] {
+        if sigma <= 0.0 {
+            return [0, 0, 0];
+        }
+
+        let ideal_width = (12.0 * sigma * sigma / 3.0 + 1.0).sqrt();
+        let mut wl = ideal_width.floor() as i32;
+        if wl < 1 {
+            wl = 1;
+        }
+        if wl % 2 == 0 {
+            wl -= 1;
+        }
+        let wu = wl + 2;
+
+        let wl_f = wl as f32;
+        let m = ((12.0 * sigma * sigma - 3.0 * wl_f * wl_f - 12.0 * wl_f - 9.0)
+            / (-4.0 * wl_f - 4.0))
+            .round() as i32;
+
+        let radius_l = (wl - 1) / 2;
+        let radius_u = (wu - 1) / 2;
+
+        let mut radii = [radius_u; 3];
+        for r in radii.iter_mut().take(m.clamp(0, 3) as usize) {
+            *r = radius_l;
+        }
+        radii
+    }
+
+    /// Approximate a true Gaussian blur of `sigma` as three successive box
+    /// blurs. The result is visually indistinguishable from [`GaussianBlur::apply`]
+    /// but, thanks to [`sliding_box_blur`], runs in time independent of the
+    /// blur strength - important since this effect is applied interactively,
+    /// before the screenshot is saved.
+    pub fn apply_fast(
+        image: &DynamicImage,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        sigma: f32,
+    ) -> DynamicImage {
+        let mut result = image.clone();
+
+        let img_width = image.width();
+        let img_height = image.height();
+
+        let x = x.min(img_width);
+        let y = y.min(img_height);
+        let width = width.min(img_width - x);
+        let height = height.min(img_height - y);
+
+        if width == 0 || height == 0 || sigma <= 0.0 {
+            return result;
+        }
+
+        let mut region = image.crop_imm(x, y, width, height).to_rgba8();
+        for radius in Self::box_radii(sigma) {
+            if radius > 0 {
+                region = sliding_box_blur(&region, radius);
+            }
+        }
+
+        if let Some(rgba) = result.as_mut_rgba8() {
+            for py in 0..height {
+                for px in 0..width {
+                    let pixel = region.get_pixel(px, py);
+                    let dest_x = x + px;
+                    let dest_y = y + py;
+                    if dest_x < img_width && dest_y < img_height {
+                        rgba.put_pixel(dest_x, dest_y, *pixel);
+                    }
+                }
+            }
+        }
+
+        result
+    }
 }
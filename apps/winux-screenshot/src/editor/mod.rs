@@ -3,11 +3,13 @@
 mod canvas;
 mod tools;
 mod blur;
+mod auto_redact;
 mod crop;
 
 pub use canvas::EditorCanvas;
 pub use tools::{Tool, ToolType, DrawingOperation};
-pub use blur::BlurEffect;
+pub use blur::{BlurEffect, RedactionMode};
+pub use auto_redact::{AutoRedactor, DetectedRegion, RedactPattern, SensitiveCategory};
 pub use crop::CropTool;
 
 use gtk4::gdk::RGBA;
@@ -0,0 +1,243 @@
+//! ALSA-backed `AudioFrontend` implementation
+
+use std::rc::Rc;
+
+use alsa::mixer::{Mixer, Selem, SelemChannelId, SelemId};
+use alsa::PollDescriptors;
+use tracing::warn;
+
+use super::{AppStream, AudioCallback, AudioError, AudioFrontend, AudioResult, AudioSignal, WatchHandle};
+use crate::config::Config;
+
+/// `AudioFrontend` backed by the system's ALSA mixer devices
+///
+/// Remembers the output/input card and channel the user last picked (or
+/// `None` if nothing has been chosen yet), so `default_output`/
+/// `default_input` can try to honor it and gracefully fall back when it's
+/// no longer available.
+pub struct AlsaBackend {
+    preferred_output: (Option<String>, Option<String>),
+    preferred_input: (Option<String>, Option<String>),
+}
+
+impl AlsaBackend {
+    pub fn new() -> Self {
+        let config = Config::load();
+        Self {
+            preferred_output: (config.audio.output_card, config.audio.output_channel),
+            preferred_input: (config.audio.input_card, config.audio.input_channel),
+        }
+    }
+
+    fn open_mixer(card: &str) -> AudioResult<Mixer> {
+        Mixer::new(card, false).map_err(|e| AudioError::Backend(e.to_string()))
+    }
+
+    fn selem<'m>(mixer: &'m Mixer, chan: &str) -> AudioResult<Selem<'m>> {
+        let sid = SelemId::new(chan, 0);
+        mixer
+            .find_selem(&sid)
+            .ok_or_else(|| AudioError::ChannelNotFound(chan.to_string(), String::new()))
+    }
+
+    /// The first card that exposes at least one playable channel
+    ///
+    /// Borrows pnmixer-rust's `AlsaCard::new` fallback: when nothing is
+    /// configured, or the configured card has disappeared, just grab
+    /// whatever is actually there instead of failing outright.
+    pub fn get_first_playable_alsa_card(&self) -> AudioResult<String> {
+        for card in self.playable_card_names()? {
+            if self
+                .playable_chan_names(&card)
+                .map(|chans| !chans.is_empty())
+                .unwrap_or(false)
+            {
+                return Ok(card);
+            }
+        }
+        Err(AudioError::CardNotFound("no playable ALSA card found".to_string()))
+    }
+
+    /// The first playable channel (`Selem`) on `card`
+    pub fn get_first_playable_selem(&self, card: &str) -> AudioResult<String> {
+        self.playable_chan_names(card)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AudioError::ChannelNotFound("(none)".to_string(), card.to_string()))
+    }
+
+    /// Resolve a preferred card/channel against what's actually available,
+    /// warning and falling back to the first playable card, then the first
+    /// playable channel on it, when the preference is unset or stale
+    fn resolve(&self, preferred: &(Option<String>, Option<String>)) -> AudioResult<(String, String)> {
+        let cards = self.playable_card_names()?;
+        let card = match &preferred.0 {
+            Some(card) if cards.iter().any(|c| c == card) => card.clone(),
+            Some(card) => {
+                warn!("Configured card '{}' is no longer available, falling back", card);
+                self.get_first_playable_alsa_card()?
+            }
+            None => self.get_first_playable_alsa_card()?,
+        };
+
+        let chans = self.playable_chan_names(&card)?;
+        let chan = match &preferred.1 {
+            Some(chan) if chans.iter().any(|c| c == chan) => chan.clone(),
+            Some(chan) => {
+                warn!(
+                    "Configured channel '{}' on card '{}' is no longer available, falling back",
+                    chan, card
+                );
+                self.get_first_playable_selem(&card)?
+            }
+            None => self.get_first_playable_selem(&card)?,
+        };
+
+        Ok((card, chan))
+    }
+}
+
+impl Default for AlsaBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioFrontend for AlsaBackend {
+    fn playable_card_names(&self) -> AudioResult<Vec<String>> {
+        let mut cards = Vec::new();
+        for card in alsa::card::Iter::new() {
+            let card = card.map_err(|e| AudioError::Backend(e.to_string()))?;
+            let name = format!("hw:{}", card.get_index());
+            if self.playable_chan_names(&name).map(|chans| !chans.is_empty()).unwrap_or(false) {
+                cards.push(name);
+            }
+        }
+        Ok(cards)
+    }
+
+    fn card_display_name(&self, card: &str) -> String {
+        alsa::card::Iter::new()
+            .filter_map(Result::ok)
+            .find(|c| format!("hw:{}", c.get_index()) == card)
+            .and_then(|c| c.get_name().ok())
+            .unwrap_or_else(|| card.to_string())
+    }
+
+    fn playable_chan_names(&self, card: &str) -> AudioResult<Vec<String>> {
+        let mixer = Self::open_mixer(card)?;
+        let names = mixer
+            .iter()
+            .filter_map(Selem::new)
+            .filter(|selem| selem.has_playback_volume())
+            .filter_map(|selem| selem.get_id().get_name().ok().map(str::to_string))
+            .collect();
+        Ok(names)
+    }
+
+    fn get_vol(&self, card: &str, chan: &str) -> AudioResult<f64> {
+        let mixer = Self::open_mixer(card)?;
+        let selem = Self::selem(&mixer, chan)?;
+        let (min, max) = selem.get_playback_volume_range();
+        let raw = selem
+            .get_playback_volume(SelemChannelId::FrontLeft)
+            .map_err(|e| AudioError::Backend(e.to_string()))?;
+        Ok(range_to_percent(raw, min, max))
+    }
+
+    fn set_vol(&self, card: &str, chan: &str, vol: f64) -> AudioResult<()> {
+        let mixer = Self::open_mixer(card)?;
+        let selem = Self::selem(&mixer, chan)?;
+        let (min, max) = selem.get_playback_volume_range();
+        selem
+            .set_playback_volume_all(percent_to_range(vol, min, max))
+            .map_err(|e| AudioError::Backend(e.to_string()))
+    }
+
+    fn get_mute(&self, card: &str, chan: &str) -> AudioResult<bool> {
+        let mixer = Self::open_mixer(card)?;
+        let selem = Self::selem(&mixer, chan)?;
+        let switch = selem
+            .get_playback_switch(SelemChannelId::FrontLeft)
+            .map_err(|e| AudioError::Backend(e.to_string()))?;
+        Ok(switch == 0)
+    }
+
+    fn set_mute(&self, card: &str, chan: &str, mute: bool) -> AudioResult<()> {
+        let mixer = Self::open_mixer(card)?;
+        let selem = Self::selem(&mixer, chan)?;
+        selem
+            .set_playback_switch_all(if mute { 0 } else { 1 })
+            .map_err(|e| AudioError::Backend(e.to_string()))
+    }
+
+    fn default_output(&self) -> AudioResult<(String, String)> {
+        self.resolve(&self.preferred_output)
+    }
+
+    fn default_input(&self) -> AudioResult<(String, String)> {
+        self.resolve(&self.preferred_input)
+    }
+
+    fn watch(&self, card: &str, callback: AudioCallback) -> AudioResult<WatchHandle> {
+        let mixer = Rc::new(Self::open_mixer(card)?);
+        let fds = mixer
+            .get()
+            .map_err(|e| AudioError::Backend(e.to_string()))?;
+
+        let callback = Rc::new(callback);
+        let source_ids = fds
+            .into_iter()
+            .map(|pfd| {
+                let mixer = mixer.clone();
+                let callback = callback.clone();
+                glib::source::unix_fd_add_local(pfd.fd, glib::IOCondition::IN, move |_, _| {
+                    match mixer.handle_events() {
+                        Ok(_) => callback(AudioSignal::ValuesChanged),
+                        Err(e) => {
+                            warn!("ALSA mixer event handling failed: {}", e);
+                            callback(AudioSignal::CardError);
+                        }
+                    }
+                    glib::ControlFlow::Continue
+                })
+            })
+            .collect();
+
+        Ok(WatchHandle::new(source_ids))
+    }
+
+    fn list_streams(&self) -> AudioResult<Vec<AppStream>> {
+        // Plain ALSA has no concept of per-application streams; only a
+        // sound server (PulseAudio/PipeWire) tracks sink inputs.
+        Ok(Vec::new())
+    }
+
+    fn set_stream_volume(&self, id: &str, _vol: f64) -> AudioResult<()> {
+        Err(AudioError::ChannelNotFound(
+            id.to_string(),
+            "per-application streams are not supported by the ALSA backend".to_string(),
+        ))
+    }
+
+    fn set_stream_mute(&self, id: &str, _mute: bool) -> AudioResult<()> {
+        Err(AudioError::ChannelNotFound(
+            id.to_string(),
+            "per-application streams are not supported by the ALSA backend".to_string(),
+        ))
+    }
+}
+
+/// Convert a raw ALSA volume in `[min, max]` to a 0.0-100.0 percentage
+fn range_to_percent(value: i64, min: i64, max: i64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+    ((value - min) as f64 / (max - min) as f64) * 100.0
+}
+
+/// Convert a 0.0-100.0 percentage to a raw ALSA volume in `[min, max]`
+fn percent_to_range(percent: f64, min: i64, max: i64) -> i64 {
+    let clamped = percent.clamp(0.0, 100.0);
+    min + ((clamped / 100.0) * (max - min) as f64).round() as i64
+}
@@ -0,0 +1,173 @@
+//! Audio backend abstraction
+//!
+//! Mirrors the card/channel model used by pnmixer-rust: a "card" is a mixer
+//! device (e.g. an ALSA hardware card), and each card exposes one or more
+//! playable "channels" that carry volume and mute state. `SoundPage` talks
+//! only to the `AudioFrontend` trait so it never depends on a specific
+//! sound stack.
+
+mod alsa;
+#[cfg(feature = "pulseaudio")]
+mod pulse;
+
+pub use alsa::AlsaBackend;
+#[cfg(feature = "pulseaudio")]
+pub use pulse::PulseBackend;
+
+use thiserror::Error;
+
+/// Audio backend errors
+#[derive(Error, Debug)]
+pub enum AudioError {
+    #[error("card not found: {0}")]
+    CardNotFound(String),
+
+    #[error("channel not found: {0} on card {1}")]
+    ChannelNotFound(String, String),
+
+    #[error("backend error: {0}")]
+    Backend(String),
+}
+
+/// Result type for audio operations
+pub type AudioResult<T> = Result<T, AudioError>;
+
+/// A single active playback stream (e.g. a PulseAudio sink input), carrying
+/// its own independent volume/mute state separate from the output device
+#[derive(Debug, Clone)]
+pub struct AppStream {
+    pub id: String,
+    pub app_name: String,
+    pub icon: String,
+    pub volume: f64,
+    pub muted: bool,
+}
+
+/// Events emitted by a backend watch: both value changes and hotplug/error
+/// conditions on the watched card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioSignal {
+    ValuesChanged,
+    CardDisconnected,
+    CardError,
+    DeviceAdded,
+    DeviceRemoved,
+}
+
+/// Callback invoked with each `AudioSignal` a watch emits
+pub type AudioCallback = Box<dyn Fn(AudioSignal)>;
+
+/// Handle to an active backend watch
+///
+/// Removes its glib event sources when dropped, so a page or backend going
+/// out of scope can't leak watches on the main loop.
+pub struct WatchHandle {
+    source_ids: Vec<glib::SourceId>,
+}
+
+impl WatchHandle {
+    fn new(source_ids: Vec<glib::SourceId>) -> Self {
+        Self { source_ids }
+    }
+}
+
+impl Drop for WatchHandle {
+    fn drop(&mut self) {
+        for id in self.source_ids.drain(..) {
+            id.remove();
+        }
+    }
+}
+
+/// Coarse classification of a channel's volume, used to pick which
+/// `audio-volume-*-symbolic` icon represents it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolLevel {
+    Off,
+    Low,
+    Medium,
+    High,
+    Muted,
+}
+
+impl VolLevel {
+    /// The icon name matching this level
+    pub fn icon_name(self) -> &'static str {
+        match self {
+            VolLevel::Off | VolLevel::Muted => "audio-volume-muted-symbolic",
+            VolLevel::Low => "audio-volume-low-symbolic",
+            VolLevel::Medium => "audio-volume-medium-symbolic",
+            VolLevel::High => "audio-volume-high-symbolic",
+        }
+    }
+}
+
+/// Classify a 0.0-100.0 volume percentage into a `VolLevel`, exactly as
+/// pnmixer-rust derives it: `muted` overrides the percentage-based result,
+/// since a muted channel should always read as `Muted` regardless of its
+/// stored volume.
+pub fn classify_volume(percent: f64, muted: bool) -> VolLevel {
+    if muted {
+        return VolLevel::Muted;
+    }
+    if percent <= 0.0 {
+        VolLevel::Off
+    } else if percent <= 33.0 {
+        VolLevel::Low
+    } else if percent <= 66.0 {
+        VolLevel::Medium
+    } else {
+        VolLevel::High
+    }
+}
+
+/// Abstraction over a system audio mixer, implemented per-backend (ALSA,
+/// PulseAudio, ...) so the UI never talks to hardware or a sound server
+/// directly.
+pub trait AudioFrontend {
+    /// List the identifiers of playable (output-capable) cards, suitable for
+    /// passing back into every other method on this trait. Use
+    /// `card_display_name` to show the user something more readable.
+    fn playable_card_names(&self) -> AudioResult<Vec<String>>;
+
+    /// A human-readable label for `card` (e.g. "HDA Intel PCH"), falling
+    /// back to `card` itself if no friendlier name is available
+    fn card_display_name(&self, card: &str) -> String {
+        card.to_string()
+    }
+
+    /// List the playable channel names on `card`
+    fn playable_chan_names(&self, card: &str) -> AudioResult<Vec<String>>;
+
+    /// Get the current volume (0.0-100.0) of `card`/`chan`
+    fn get_vol(&self, card: &str, chan: &str) -> AudioResult<f64>;
+
+    /// Set the volume (0.0-100.0) of `card`/`chan`
+    fn set_vol(&self, card: &str, chan: &str, vol: f64) -> AudioResult<()>;
+
+    /// Get whether `card`/`chan` is muted
+    fn get_mute(&self, card: &str, chan: &str) -> AudioResult<bool>;
+
+    /// Set whether `card`/`chan` is muted
+    fn set_mute(&self, card: &str, chan: &str, mute: bool) -> AudioResult<()>;
+
+    /// The card/channel pair currently used as the default output
+    fn default_output(&self) -> AudioResult<(String, String)>;
+
+    /// The card/channel pair currently used as the default input
+    fn default_input(&self) -> AudioResult<(String, String)>;
+
+    /// Watch `card` for changes made by other apps or hardware keys,
+    /// invoking `callback` with an `AudioSignal` as they happen
+    fn watch(&self, card: &str, callback: AudioCallback) -> AudioResult<WatchHandle>;
+
+    /// List currently active playback streams, one per application
+    /// actively playing audio
+    fn list_streams(&self) -> AudioResult<Vec<AppStream>>;
+
+    /// Set an active stream's volume
+    fn set_stream_volume(&self, id: &str, vol: f64) -> AudioResult<()>;
+
+    /// Set an active stream's mute state
+    fn set_stream_mute(&self, id: &str, mute: bool) -> AudioResult<()>;
+}
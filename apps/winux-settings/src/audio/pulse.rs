@@ -0,0 +1,72 @@
+//! PulseAudio `AudioFrontend` implementation (stub)
+//!
+//! Not wired up to `libpulse` yet; exists so `SoundPage` can be written
+//! against `AudioFrontend` without committing to ALSA, and so a real
+//! implementation can be dropped in later without touching the UI. Enabled
+//! via the `pulseaudio` Cargo feature.
+
+use super::{AppStream, AudioCallback, AudioError, AudioFrontend, AudioResult, WatchHandle};
+
+/// `AudioFrontend` for PulseAudio; it holds no sound-server connection, and
+/// `new` and every method return an error
+pub struct PulseBackend;
+
+impl PulseBackend {
+    pub fn new() -> AudioResult<Self> {
+        Err(unimplemented_error())
+    }
+}
+
+impl AudioFrontend for PulseBackend {
+    fn playable_card_names(&self) -> AudioResult<Vec<String>> {
+        Err(unimplemented_error())
+    }
+
+    fn playable_chan_names(&self, _card: &str) -> AudioResult<Vec<String>> {
+        Err(unimplemented_error())
+    }
+
+    fn get_vol(&self, _card: &str, _chan: &str) -> AudioResult<f64> {
+        Err(unimplemented_error())
+    }
+
+    fn set_vol(&self, _card: &str, _chan: &str, _vol: f64) -> AudioResult<()> {
+        Err(unimplemented_error())
+    }
+
+    fn get_mute(&self, _card: &str, _chan: &str) -> AudioResult<bool> {
+        Err(unimplemented_error())
+    }
+
+    fn set_mute(&self, _card: &str, _chan: &str, _mute: bool) -> AudioResult<()> {
+        Err(unimplemented_error())
+    }
+
+    fn default_output(&self) -> AudioResult<(String, String)> {
+        Err(unimplemented_error())
+    }
+
+    fn default_input(&self) -> AudioResult<(String, String)> {
+        Err(unimplemented_error())
+    }
+
+    fn watch(&self, _card: &str, _callback: AudioCallback) -> AudioResult<WatchHandle> {
+        Err(unimplemented_error())
+    }
+
+    fn list_streams(&self) -> AudioResult<Vec<AppStream>> {
+        Err(unimplemented_error())
+    }
+
+    fn set_stream_volume(&self, _id: &str, _vol: f64) -> AudioResult<()> {
+        Err(unimplemented_error())
+    }
+
+    fn set_stream_mute(&self, _id: &str, _mute: bool) -> AudioResult<()> {
+        Err(unimplemented_error())
+    }
+}
+
+fn unimplemented_error() -> AudioError {
+    AudioError::Backend("PulseAudio backend is not yet implemented".to_string())
+}
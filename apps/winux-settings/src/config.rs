@@ -0,0 +1,77 @@
+//! Configuration management for Winux Settings
+//!
+//! Handles loading and saving user preferences, such as the selected audio
+//! output/input card and channel.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+/// Main configuration structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Audio device selection
+    #[serde(default)]
+    pub audio: AudioConfig,
+}
+
+/// Persisted audio card/channel selection
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AudioConfig {
+    pub output_card: Option<String>,
+    pub output_channel: Option<String>,
+    pub input_card: Option<String>,
+    pub input_channel: Option<String>,
+}
+
+impl Config {
+    /// Load configuration from file
+    pub fn load() -> Self {
+        let config_path = Self::config_path();
+
+        if config_path.exists() {
+            match std::fs::read_to_string(&config_path) {
+                Ok(contents) => match toml::from_str(&contents) {
+                    Ok(config) => {
+                        info!("Configuration loaded from {:?}", config_path);
+                        return config;
+                    }
+                    Err(e) => {
+                        warn!("Failed to parse config file: {}", e);
+                    }
+                },
+                Err(e) => {
+                    warn!("Failed to read config file: {}", e);
+                }
+            }
+        }
+
+        info!("Using default configuration");
+        Self::default()
+    }
+
+    /// Save configuration to file
+    pub fn save(&self) -> anyhow::Result<()> {
+        let config_path = Self::config_path();
+
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let contents = toml::to_string_pretty(self)?;
+        std::fs::write(&config_path, contents)?;
+
+        info!("Configuration saved to {:?}", config_path);
+        Ok(())
+    }
+
+    /// Get the configuration file path
+    fn config_path() -> PathBuf {
+        directories::ProjectDirs::from("org", "winux", "settings")
+            .map(|dirs| dirs.config_dir().join("config.toml"))
+            .unwrap_or_else(|| {
+                let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+                PathBuf::from(home).join(".config/winux/settings/config.toml")
+            })
+    }
+}
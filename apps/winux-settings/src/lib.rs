@@ -2,6 +2,8 @@
 //!
 //! This library provides the core functionality for the Winux Settings application.
 
+pub mod audio;
+pub mod config;
 pub mod pages;
 
 pub use pages::{
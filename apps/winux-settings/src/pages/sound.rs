@@ -1,18 +1,35 @@
 //! Sound settings page
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use gtk4::prelude::*;
 use libadwaita as adw;
 use libadwaita::prelude::*;
-use tracing::info;
+use tracing::{info, warn};
+
+use crate::audio::{classify_volume, AlsaBackend, AudioCallback, AudioFrontend, AudioSignal, WatchHandle};
+use crate::config::Config;
 
 /// Sound settings page
 pub struct SoundPage {
     widget: adw::PreferencesPage,
+    _output_watch: Option<WatchHandle>,
+    _input_watch: Option<WatchHandle>,
 }
 
 impl SoundPage {
     /// Create a new sound settings page
     pub fn new() -> Self {
+        let backend: Rc<dyn AudioFrontend> = Rc::new(AlsaBackend::new());
+
+        // Application-volumes group; created up front so the output watch
+        // below can refresh it whenever streams appear or disappear
+        let apps_group = adw::PreferencesGroup::new();
+        apps_group.set_title("Application Volumes");
+        apps_group.set_description(Some("Control volume for individual applications"));
+        let app_rows: Rc<RefCell<Vec<adw::ActionRow>>> = Rc::new(RefCell::new(Vec::new()));
+
         let page = adw::PreferencesPage::new();
         page.set_title("Sound");
         page.set_icon_name(Some("audio-speakers-symbolic"));
@@ -22,36 +39,200 @@ impl SoundPage {
         output_group.set_title("Output");
         output_group.set_description(Some("Configure audio output devices"));
 
+        // Only list cards that actually expose a playable channel, so the
+        // combo never offers a device that would immediately show "No
+        // channel found" once selected
+        let output_cards: Vec<String> = backend
+            .playable_card_names()
+            .unwrap_or_else(|e| {
+                warn!("Failed to list output cards: {}", e);
+                Vec::new()
+            })
+            .into_iter()
+            .filter(|card| {
+                backend
+                    .playable_chan_names(card)
+                    .map(|chans| !chans.is_empty())
+                    .unwrap_or(false)
+            })
+            .collect();
+        let (resolved_output_card, resolved_output_chan) =
+            backend.default_output().unwrap_or_else(|e| {
+                warn!("Failed to determine default output: {}", e);
+                (String::new(), String::new())
+            });
+        let output_card: Rc<RefCell<String>> = Rc::new(RefCell::new(resolved_output_card.clone()));
+        let output_chan: Rc<RefCell<String>> = Rc::new(RefCell::new(resolved_output_chan.clone()));
+
         // Output device
         let output_device = adw::ComboRow::new();
         output_device.set_title("Output Device");
         output_device.set_subtitle("Select default audio output");
-        let devices = gtk4::StringList::new(&[
-            "Built-in Speakers",
-            "HDMI Output",
-            "USB Headset",
-            "Bluetooth Headphones",
-        ]);
-        output_device.set_model(Some(&devices));
+        let output_labels: Vec<String> = if output_cards.is_empty() {
+            vec!["No output device found".to_string()]
+        } else {
+            output_cards
+                .iter()
+                .map(|c| backend.card_display_name(c))
+                .collect()
+        };
+        let output_names: Vec<&str> = output_labels.iter().map(String::as_str).collect();
+        output_device.set_model(Some(&gtk4::StringList::new(&output_names)));
+        if let Some(pos) = output_cards.iter().position(|c| *c == resolved_output_card) {
+            output_device.set_selected(pos as u32);
+        }
         output_group.add(&output_device);
 
+        // Output channel
+        let output_channel = adw::ComboRow::new();
+        output_channel.set_title("Output Channel");
+        let output_chans = backend
+            .playable_chan_names(&resolved_output_card)
+            .unwrap_or_default();
+        let output_chan_names: Vec<&str> = if output_chans.is_empty() {
+            vec!["No channel found"]
+        } else {
+            output_chans.iter().map(String::as_str).collect()
+        };
+        output_channel.set_model(Some(&gtk4::StringList::new(&output_chan_names)));
+        if let Some(pos) = output_chans.iter().position(|c| *c == resolved_output_chan) {
+            output_channel.set_selected(pos as u32);
+        }
+        output_group.add(&output_channel);
+
         // Master volume
         let volume_row = adw::ActionRow::new();
         volume_row.set_title("Volume");
 
         let volume_scale = gtk4::Scale::with_range(gtk4::Orientation::Horizontal, 0.0, 100.0, 1.0);
-        volume_scale.set_value(80.0);
+        let current_vol = backend
+            .get_vol(&resolved_output_card, &resolved_output_chan)
+            .unwrap_or_else(|e| {
+                warn!("Failed to read output volume: {}", e);
+                0.0
+            });
+        volume_scale.set_value(current_vol);
         volume_scale.set_draw_value(true);
         volume_scale.set_width_request(200);
         volume_scale.set_hexpand(true);
 
         let mute_btn = gtk4::ToggleButton::new();
-        mute_btn.set_icon_name("audio-volume-high-symbolic");
-        mute_btn.connect_toggled(|btn| {
-            if btn.is_active() {
-                btn.set_icon_name("audio-volume-muted-symbolic");
-            } else {
-                btn.set_icon_name("audio-volume-high-symbolic");
+        let muted = backend
+            .get_mute(&resolved_output_card, &resolved_output_chan)
+            .unwrap_or_else(|e| {
+                warn!("Failed to read output mute state: {}", e);
+                false
+            });
+        mute_btn.set_active(muted);
+        mute_btn.set_icon_name(classify_volume(current_vol, muted).icon_name());
+
+        volume_scale.connect_value_changed({
+            let backend = backend.clone();
+            let output_card = output_card.clone();
+            let output_chan = output_chan.clone();
+            let mute_btn = mute_btn.clone();
+            move |scale| {
+                let vol = scale.value();
+                mute_btn.set_icon_name(classify_volume(vol, mute_btn.is_active()).icon_name());
+                if let Err(e) = backend.set_vol(&output_card.borrow(), &output_chan.borrow(), vol) {
+                    warn!("Failed to set output volume: {}", e);
+                }
+            }
+        });
+
+        mute_btn.connect_toggled({
+            let backend = backend.clone();
+            let output_card = output_card.clone();
+            let output_chan = output_chan.clone();
+            let volume_scale = volume_scale.clone();
+            move |btn| {
+                let muted = btn.is_active();
+                btn.set_icon_name(classify_volume(volume_scale.value(), muted).icon_name());
+                if let Err(e) = backend.set_mute(&output_card.borrow(), &output_chan.borrow(), muted) {
+                    warn!("Failed to set output mute: {}", e);
+                }
+            }
+        });
+
+        // Re-enumerate channels and persist the selection when the output
+        // device changes; falls back gracefully if the card has no channels
+        output_device.connect_selected_notify({
+            let backend = backend.clone();
+            let output_cards = output_cards.clone();
+            let output_card = output_card.clone();
+            let output_chan = output_chan.clone();
+            let output_channel = output_channel.clone();
+            let volume_scale = volume_scale.clone();
+            let mute_btn = mute_btn.clone();
+            move |combo| {
+                let Some(card) = output_cards.get(combo.selected() as usize) else {
+                    return;
+                };
+                *output_card.borrow_mut() = card.clone();
+
+                let chans = backend.playable_chan_names(card).unwrap_or_else(|e| {
+                    warn!("Failed to list channels for card '{}': {}", card, e);
+                    Vec::new()
+                });
+                let chan_names: Vec<&str> = if chans.is_empty() {
+                    vec!["No channel found"]
+                } else {
+                    chans.iter().map(String::as_str).collect()
+                };
+                output_channel.set_model(Some(&gtk4::StringList::new(&chan_names)));
+                let chan = chans.first().cloned().unwrap_or_default();
+                if !chans.is_empty() {
+                    output_channel.set_selected(0);
+                }
+                *output_chan.borrow_mut() = chan.clone();
+
+                if let Ok(vol) = backend.get_vol(card, &chan) {
+                    volume_scale.set_value(vol);
+                }
+                if let Ok(vol_muted) = backend.get_mute(card, &chan) {
+                    mute_btn.set_active(vol_muted);
+                    mute_btn.set_icon_name(classify_volume(volume_scale.value(), vol_muted).icon_name());
+                }
+
+                let mut config = Config::load();
+                config.audio.output_card = Some(card.clone());
+                config.audio.output_channel = Some(chan);
+                if let Err(e) = config.save() {
+                    warn!("Failed to persist output device selection: {}", e);
+                }
+            }
+        });
+
+        // Persist the channel selection when it changes on its own (the
+        // device combo above already persists when both change together)
+        output_channel.connect_selected_notify({
+            let backend = backend.clone();
+            let output_card = output_card.clone();
+            let output_chan = output_chan.clone();
+            let volume_scale = volume_scale.clone();
+            let mute_btn = mute_btn.clone();
+            move |combo| {
+                let Some(model) = combo.model() else { return };
+                let Some(item) = model.item(combo.selected()) else { return };
+                let Some(chan) = item.downcast_ref::<gtk4::StringObject>() else { return };
+                let chan = chan.string().to_string();
+                *output_chan.borrow_mut() = chan.clone();
+
+                let card = output_card.borrow().clone();
+                if let Ok(vol) = backend.get_vol(&card, &chan) {
+                    volume_scale.set_value(vol);
+                }
+                if let Ok(muted) = backend.get_mute(&card, &chan) {
+                    mute_btn.set_active(muted);
+                    mute_btn.set_icon_name(classify_volume(volume_scale.value(), muted).icon_name());
+                }
+
+                let mut config = Config::load();
+                config.audio.output_card = Some(card);
+                config.audio.output_channel = Some(chan);
+                if let Err(e) = config.save() {
+                    warn!("Failed to persist output channel selection: {}", e);
+                }
             }
         });
 
@@ -61,6 +242,39 @@ impl SoundPage {
         volume_row.add_suffix(&vol_box);
         output_group.add(&volume_row);
 
+        // Reflect volume/mute changes made by other apps or hardware keys,
+        // and refresh the application-volumes list on any backend event
+        let output_watch_callback: AudioCallback = {
+            let backend = backend.clone();
+            let output_card = output_card.clone();
+            let output_chan = output_chan.clone();
+            let volume_scale = volume_scale.clone();
+            let mute_btn = mute_btn.clone();
+            let apps_group = apps_group.clone();
+            let app_rows = app_rows.clone();
+            Box::new(move |signal| {
+                if signal == AudioSignal::ValuesChanged {
+                    let card = output_card.borrow();
+                    let chan = output_chan.borrow();
+                    if let Ok(vol) = backend.get_vol(&card, &chan) {
+                        volume_scale.set_value(vol);
+                    }
+                    if let Ok(muted) = backend.get_mute(&card, &chan) {
+                        mute_btn.set_active(muted);
+                        mute_btn.set_icon_name(classify_volume(volume_scale.value(), muted).icon_name());
+                    }
+                }
+                Self::rebuild_apps(&apps_group, &app_rows, &backend);
+            })
+        };
+        let output_watch = match backend.watch(&resolved_output_card, output_watch_callback) {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                warn!("Failed to watch output card for changes: {}", e);
+                None
+            }
+        };
+
         // Balance
         let balance_row = adw::ActionRow::new();
         balance_row.set_title("Balance");
@@ -83,29 +297,163 @@ impl SoundPage {
         input_group.set_title("Input");
         input_group.set_description(Some("Configure audio input devices"));
 
+        // Only list cards that actually expose a playable channel; see the
+        // matching comment in the output group above.
+        let input_cards: Vec<String> = backend
+            .playable_card_names()
+            .unwrap_or_else(|e| {
+                warn!("Failed to list input cards: {}", e);
+                Vec::new()
+            })
+            .into_iter()
+            .filter(|card| {
+                backend
+                    .playable_chan_names(card)
+                    .map(|chans| !chans.is_empty())
+                    .unwrap_or(false)
+            })
+            .collect();
+        let (resolved_input_card, resolved_input_chan) =
+            backend.default_input().unwrap_or_else(|e| {
+                warn!("Failed to determine default input: {}", e);
+                (String::new(), String::new())
+            });
+        let input_card: Rc<RefCell<String>> = Rc::new(RefCell::new(resolved_input_card.clone()));
+        let input_chan: Rc<RefCell<String>> = Rc::new(RefCell::new(resolved_input_chan.clone()));
+
         // Input device
         let input_device = adw::ComboRow::new();
         input_device.set_title("Input Device");
         input_device.set_subtitle("Select default microphone");
-        let mics = gtk4::StringList::new(&[
-            "Built-in Microphone",
-            "USB Headset Microphone",
-            "Webcam Microphone",
-        ]);
-        input_device.set_model(Some(&mics));
+        let input_labels: Vec<String> = if input_cards.is_empty() {
+            vec!["No input device found".to_string()]
+        } else {
+            input_cards
+                .iter()
+                .map(|c| backend.card_display_name(c))
+                .collect()
+        };
+        let input_names: Vec<&str> = input_labels.iter().map(String::as_str).collect();
+        input_device.set_model(Some(&gtk4::StringList::new(&input_names)));
+        if let Some(pos) = input_cards.iter().position(|c| *c == resolved_input_card) {
+            input_device.set_selected(pos as u32);
+        }
         input_group.add(&input_device);
 
+        // Input channel
+        let input_channel = adw::ComboRow::new();
+        input_channel.set_title("Input Channel");
+        let input_chans = backend
+            .playable_chan_names(&resolved_input_card)
+            .unwrap_or_default();
+        let input_chan_names: Vec<&str> = if input_chans.is_empty() {
+            vec!["No channel found"]
+        } else {
+            input_chans.iter().map(String::as_str).collect()
+        };
+        input_channel.set_model(Some(&gtk4::StringList::new(&input_chan_names)));
+        if let Some(pos) = input_chans.iter().position(|c| *c == resolved_input_chan) {
+            input_channel.set_selected(pos as u32);
+        }
+        input_group.add(&input_channel);
+
         // Input volume
         let input_vol_row = adw::ActionRow::new();
         input_vol_row.set_title("Input Volume");
 
         let input_scale = gtk4::Scale::with_range(gtk4::Orientation::Horizontal, 0.0, 100.0, 1.0);
-        input_scale.set_value(70.0);
+        let current_input_vol = backend
+            .get_vol(&resolved_input_card, &resolved_input_chan)
+            .unwrap_or_else(|e| {
+                warn!("Failed to read input volume: {}", e);
+                0.0
+            });
+        input_scale.set_value(current_input_vol);
         input_scale.set_draw_value(true);
         input_scale.set_width_request(200);
+        input_scale.connect_value_changed({
+            let backend = backend.clone();
+            let input_card = input_card.clone();
+            let input_chan = input_chan.clone();
+            move |scale| {
+                if let Err(e) = backend.set_vol(&input_card.borrow(), &input_chan.borrow(), scale.value()) {
+                    warn!("Failed to set input volume: {}", e);
+                }
+            }
+        });
         input_vol_row.add_suffix(&input_scale);
         input_group.add(&input_vol_row);
 
+        // Re-enumerate channels and persist the selection when the input
+        // device changes; falls back gracefully if the card has no channels
+        input_device.connect_selected_notify({
+            let backend = backend.clone();
+            let input_cards = input_cards.clone();
+            let input_card = input_card.clone();
+            let input_chan = input_chan.clone();
+            let input_channel = input_channel.clone();
+            let input_scale = input_scale.clone();
+            move |combo| {
+                let Some(card) = input_cards.get(combo.selected() as usize) else {
+                    return;
+                };
+                *input_card.borrow_mut() = card.clone();
+
+                let chans = backend.playable_chan_names(card).unwrap_or_else(|e| {
+                    warn!("Failed to list channels for card '{}': {}", card, e);
+                    Vec::new()
+                });
+                let chan_names: Vec<&str> = if chans.is_empty() {
+                    vec!["No channel found"]
+                } else {
+                    chans.iter().map(String::as_str).collect()
+                };
+                input_channel.set_model(Some(&gtk4::StringList::new(&chan_names)));
+                let chan = chans.first().cloned().unwrap_or_default();
+                if !chans.is_empty() {
+                    input_channel.set_selected(0);
+                }
+                *input_chan.borrow_mut() = chan.clone();
+
+                if let Ok(vol) = backend.get_vol(card, &chan) {
+                    input_scale.set_value(vol);
+                }
+
+                let mut config = Config::load();
+                config.audio.input_card = Some(card.clone());
+                config.audio.input_channel = Some(chan);
+                if let Err(e) = config.save() {
+                    warn!("Failed to persist input device selection: {}", e);
+                }
+            }
+        });
+
+        input_channel.connect_selected_notify({
+            let backend = backend.clone();
+            let input_card = input_card.clone();
+            let input_chan = input_chan.clone();
+            let input_scale = input_scale.clone();
+            move |combo| {
+                let Some(model) = combo.model() else { return };
+                let Some(item) = model.item(combo.selected()) else { return };
+                let Some(chan) = item.downcast_ref::<gtk4::StringObject>() else { return };
+                let chan = chan.string().to_string();
+                *input_chan.borrow_mut() = chan.clone();
+
+                let card = input_card.borrow().clone();
+                if let Ok(vol) = backend.get_vol(&card, &chan) {
+                    input_scale.set_value(vol);
+                }
+
+                let mut config = Config::load();
+                config.audio.input_card = Some(card);
+                config.audio.input_channel = Some(chan);
+                if let Err(e) = config.save() {
+                    warn!("Failed to persist input channel selection: {}", e);
+                }
+            }
+        });
+
         // Input level meter
         let level_row = adw::ActionRow::new();
         level_row.set_title("Input Level");
@@ -114,11 +462,36 @@ impl SoundPage {
         let level_bar = gtk4::LevelBar::new();
         level_bar.set_min_value(0.0);
         level_bar.set_max_value(1.0);
-        level_bar.set_value(0.3);
+        level_bar.set_value(current_input_vol / 100.0);
         level_bar.set_width_request(200);
         level_row.add_suffix(&level_bar);
         input_group.add(&level_row);
 
+        // Reflect input volume changes made by other apps
+        let input_watch_callback: AudioCallback = {
+            let backend = backend.clone();
+            let input_card = input_card.clone();
+            let input_chan = input_chan.clone();
+            let input_scale = input_scale.clone();
+            let level_bar = level_bar.clone();
+            Box::new(move |signal| {
+                if signal != AudioSignal::ValuesChanged {
+                    return;
+                }
+                if let Ok(vol) = backend.get_vol(&input_card.borrow(), &input_chan.borrow()) {
+                    input_scale.set_value(vol);
+                    level_bar.set_value(vol / 100.0);
+                }
+            })
+        };
+        let input_watch = match backend.watch(&resolved_input_card, input_watch_callback) {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                warn!("Failed to watch input card for changes: {}", e);
+                None
+            }
+        };
+
         // Noise cancellation
         let noise_row = adw::ActionRow::new();
         noise_row.set_title("Noise Cancellation");
@@ -212,34 +585,65 @@ impl SoundPage {
         page.add(&profiles_group);
 
         // Applications group
-        let apps_group = adw::PreferencesGroup::new();
-        apps_group.set_title("Application Volumes");
-        apps_group.set_description(Some("Control volume for individual applications"));
-
-        // Placeholder for running apps
-        let app1_row = adw::ActionRow::new();
-        app1_row.set_title("Firefox");
-        app1_row.set_subtitle("Web Browser");
-
-        let app1_scale = gtk4::Scale::with_range(gtk4::Orientation::Horizontal, 0.0, 100.0, 1.0);
-        app1_scale.set_value(100.0);
-        app1_scale.set_width_request(150);
-        app1_row.add_suffix(&app1_scale);
-        apps_group.add(&app1_row);
-
-        let app2_row = adw::ActionRow::new();
-        app2_row.set_title("Spotify");
-        app2_row.set_subtitle("Music Player");
+        Self::rebuild_apps(&apps_group, &app_rows, &backend);
+        page.add(&apps_group);
 
-        let app2_scale = gtk4::Scale::with_range(gtk4::Orientation::Horizontal, 0.0, 100.0, 1.0);
-        app2_scale.set_value(80.0);
-        app2_scale.set_width_request(150);
-        app2_row.add_suffix(&app2_scale);
-        apps_group.add(&app2_row);
+        SoundPage {
+            widget: page,
+            _output_watch: output_watch,
+            _input_watch: input_watch,
+        }
+    }
 
-        page.add(&apps_group);
+    /// Replace the application-volumes list with the backend's current
+    /// active playback streams
+    fn rebuild_apps(
+        apps_group: &adw::PreferencesGroup,
+        app_rows: &Rc<RefCell<Vec<adw::ActionRow>>>,
+        backend: &Rc<dyn AudioFrontend>,
+    ) {
+        for row in app_rows.borrow_mut().drain(..) {
+            apps_group.remove(&row);
+        }
+
+        let streams = backend.list_streams().unwrap_or_else(|e| {
+            warn!("Failed to list application streams: {}", e);
+            Vec::new()
+        });
 
-        SoundPage { widget: page }
+        if streams.is_empty() {
+            let row = adw::ActionRow::builder()
+                .title("No applications playing audio")
+                .build();
+            apps_group.add(&row);
+            app_rows.borrow_mut().push(row);
+            return;
+        }
+
+        for stream in streams {
+            let row = adw::ActionRow::new();
+            row.set_title(&stream.app_name);
+
+            let icon = gtk4::Image::from_icon_name(&stream.icon);
+            row.add_prefix(&icon);
+
+            let scale = gtk4::Scale::with_range(gtk4::Orientation::Horizontal, 0.0, 100.0, 1.0);
+            scale.set_value(stream.volume);
+            scale.set_width_request(150);
+            scale.connect_value_changed({
+                let backend = backend.clone();
+                let id = stream.id.clone();
+                move |scale| {
+                    if let Err(e) = backend.set_stream_volume(&id, scale.value()) {
+                        warn!("Failed to set stream volume: {}", e);
+                    }
+                }
+            });
+            row.add_suffix(&scale);
+
+            apps_group.add(&row);
+            app_rows.borrow_mut().push(row);
+        }
     }
 
     /// Get the page widget
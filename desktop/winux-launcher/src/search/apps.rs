@@ -1,10 +1,9 @@
 //! Application search provider
 
 use crate::config::Config;
-use crate::search::{SearchCategory, SearchResult, SearchResultKind};
+use crate::search::{LauncherProvider, SearchCategory, SearchContext, SearchResult, SearchResultKind};
 use freedesktop_desktop_entry::{DesktopEntry, Iter};
 use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -120,38 +119,39 @@ impl AppSearcher {
             no_display,
         })
     }
+}
 
+impl LauncherProvider for AppSearcher {
     /// Search applications
-    pub fn search(&self, query: &str) -> Vec<SearchResult> {
-        let query_lower = query.to_lowercase();
-        let mut results: Vec<(i64, SearchResult)> = Vec::new();
+    fn search(&self, ctx: &SearchContext) -> Vec<SearchResult> {
+        let mut results: Vec<(u32, SearchResult)> = Vec::new();
 
         for app in &self.apps {
             // Calculate match score
-            let mut best_score: i64 = 0;
+            let mut best_score: u32 = 0;
 
             // Match against name
-            if let Some(score) = self.matcher.fuzzy_match(&app.name.to_lowercase(), &query_lower) {
+            if let Some(score) = ctx.score(&self.matcher, &app.name) {
                 best_score = best_score.max(score);
             }
 
             // Match against generic name
             if let Some(ref generic) = app.generic_name {
-                if let Some(score) = self.matcher.fuzzy_match(&generic.to_lowercase(), &query_lower) {
+                if let Some(score) = ctx.score(&self.matcher, generic) {
                     best_score = best_score.max(score);
                 }
             }
 
             // Match against keywords
             for keyword in &app.keywords {
-                if let Some(score) = self.matcher.fuzzy_match(&keyword.to_lowercase(), &query_lower) {
+                if let Some(score) = ctx.score(&self.matcher, keyword) {
                     best_score = best_score.max(score);
                 }
             }
 
             // Match against categories
             for category in &app.categories {
-                if let Some(score) = self.matcher.fuzzy_match(&category.to_lowercase(), &query_lower) {
+                if let Some(score) = ctx.score(&self.matcher, category) {
                     best_score = best_score.max(score / 2); // Lower weight for categories
                 }
             }
@@ -172,7 +172,7 @@ impl AppSearcher {
                         exec: app.exec.clone(),
                         categories: app.categories.clone(),
                     },
-                    score: (best_score.min(100) as u32),
+                    score: best_score.min(100),
                     from_history: false,
                 };
 
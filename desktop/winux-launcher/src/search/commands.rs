@@ -1,11 +1,33 @@
 //! System commands provider
 
-use crate::search::{SearchCategory, SearchResult, SearchResultKind};
+use crate::search::{LauncherProvider, SearchCategory, SearchContext, SearchResult, SearchResultKind};
 use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+/// Default number of command results `CommandSearcher::search` returns;
+/// override with `CommandSearcher::set_max_results`
+const DEFAULT_MAX_RESULTS: usize = 3;
 
 /// System command definition
 struct SystemCommand {
+    name: String,
+    aliases: Vec<String>,
+    description: String,
+    icon: String,
+    command: String,
+    dangerous: bool,
+    /// Whether `command` is a raw shell command line rather than one of the
+    /// built-in keywords handled by `execute_command`
+    shell: bool,
+}
+
+/// Built-in command definition, as a `'static` table entry
+struct BuiltinCommand {
     name: &'static str,
     aliases: &'static [&'static str],
     description: &'static str,
@@ -14,9 +36,9 @@ struct SystemCommand {
     dangerous: bool,
 }
 
-/// Available system commands
-const COMMANDS: &[SystemCommand] = &[
-    SystemCommand {
+/// Available built-in commands
+const COMMANDS: &[BuiltinCommand] = &[
+    BuiltinCommand {
         name: "Shutdown",
         aliases: &["poweroff", "power off", "turn off", "desligar"],
         description: "Turn off the computer",
@@ -24,7 +46,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "shutdown",
         dangerous: true,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Restart",
         aliases: &["reboot", "reiniciar"],
         description: "Restart the computer",
@@ -32,7 +54,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "restart",
         dangerous: true,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Log Out",
         aliases: &["logout", "sign out", "sair"],
         description: "Log out of your session",
@@ -40,7 +62,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "logout",
         dangerous: true,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Lock Screen",
         aliases: &["lock", "bloquear"],
         description: "Lock the screen",
@@ -48,7 +70,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "lock",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Sleep",
         aliases: &["suspend", "suspender"],
         description: "Put the computer to sleep",
@@ -56,7 +78,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "sleep",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Settings",
         aliases: &["preferences", "config", "configuracoes"],
         description: "Open system settings",
@@ -64,7 +86,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "settings",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Files",
         aliases: &["file manager", "nautilus", "arquivos"],
         description: "Open file manager",
@@ -72,7 +94,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "files",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Terminal",
         aliases: &["console", "shell", "cmd"],
         description: "Open terminal",
@@ -80,7 +102,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "terminal",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Display Settings",
         aliases: &["monitor", "screen", "display"],
         description: "Configure displays",
@@ -88,7 +110,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "settings display",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Sound Settings",
         aliases: &["audio", "volume", "som"],
         description: "Configure sound",
@@ -96,7 +118,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "settings sound",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Network Settings",
         aliases: &["wifi", "internet", "rede"],
         description: "Configure network",
@@ -104,7 +126,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "settings network",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Bluetooth Settings",
         aliases: &["bluetooth"],
         description: "Configure Bluetooth",
@@ -112,7 +134,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "settings bluetooth",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "About This Computer",
         aliases: &["system info", "about", "sobre"],
         description: "View system information",
@@ -120,7 +142,7 @@ const COMMANDS: &[SystemCommand] = &[
         command: "about",
         dangerous: false,
     },
-    SystemCommand {
+    BuiltinCommand {
         name: "Empty Trash",
         aliases: &["trash", "lixeira"],
         description: "Empty the trash",
@@ -130,70 +152,349 @@ const COMMANDS: &[SystemCommand] = &[
     },
 ];
 
+/// A single `[[command]]` entry in `~/.config/winux/commands.toml`
+#[derive(Debug, Clone, Deserialize)]
+struct UserCommandEntry {
+    name: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    description: String,
+    #[serde(default = "default_icon")]
+    icon: String,
+    #[serde(default)]
+    command: String,
+    #[serde(default)]
+    dangerous: bool,
+    #[serde(default)]
+    shell: bool,
+}
+
+fn default_icon() -> String {
+    "system-run-symbolic".to_string()
+}
+
+/// Root of `commands.toml`: a list of `[[command]]` tables
+#[derive(Debug, Default, Deserialize)]
+struct UserCommandsFile {
+    #[serde(rename = "command", default)]
+    command: Vec<UserCommandEntry>,
+}
+
 /// System command searcher
 pub struct CommandSearcher {
     matcher: SkimMatcherV2,
+    commands: Vec<SystemCommand>,
+    usage: RefCell<UsageMap>,
+    max_results: usize,
 }
 
 impl CommandSearcher {
-    /// Create new command searcher
+    /// Create new command searcher, merging user-defined commands from
+    /// `~/.config/winux/commands.toml` on top of the built-ins (a user entry
+    /// with the same `name` as a built-in replaces it) and restoring any
+    /// persisted usage history for frecency ranking
     pub fn new() -> Self {
+        let mut commands = builtin_commands();
+
+        for user_command in load_user_commands() {
+            if let Some(existing) = commands.iter_mut().find(|c| c.name == user_command.name) {
+                *existing = user_command;
+            } else {
+                commands.push(user_command);
+            }
+        }
+
         Self {
             matcher: SkimMatcherV2::default(),
+            commands,
+            usage: RefCell::new(load_usage()),
+            max_results: DEFAULT_MAX_RESULTS,
         }
     }
 
-    /// Search for matching commands
-    pub fn search(&self, query: &str) -> Vec<SearchResult> {
-        let query_lower = query.to_lowercase();
+    /// Override how many command results `search` returns (default 3)
+    pub fn set_max_results(&mut self, max_results: usize) {
+        self.max_results = max_results;
+    }
+
+    /// Record that `command` was launched, boosting its future frecency
+    /// ranking, and persist the updated usage store to disk
+    pub fn record_use(&self, command: &str) {
+        let mut usage = self.usage.borrow_mut();
+        let entry = usage.entry(command.to_string()).or_default();
+        entry.count += 1;
+        entry.last_used_secs = now_unix_seconds();
+        save_usage(&usage);
+    }
+
+    /// Frecency boost for `command`: full weight if used within the last
+    /// hour, halving every three days after that, multiplied by how many
+    /// times it's been used and capped so history can't bury a handful of
+    /// strong fuzzy matches
+    fn frecency_boost(&self, command: &str) -> i64 {
+        let usage = self.usage.borrow();
+        let Some(entry) = usage.get(command) else {
+            return 0;
+        };
+
+        let age_hours = now_unix_seconds().saturating_sub(entry.last_used_secs) as f64 / 3600.0;
+        let recency_weight = if age_hours <= 1.0 {
+            1.0
+        } else {
+            0.5f64.powf((age_hours - 1.0) / (3.0 * 24.0))
+        };
+
+        let frecency = recency_weight * entry.count as f64;
+        (frecency.min(5.0) * 6.0) as i64
+    }
+
+    /// Search for matching commands and return the requested page of
+    /// results (0-indexed), along with the total match count and whether
+    /// further pages exist, instead of discarding everything past the top
+    /// few matches.
+    pub fn search_paged(&self, ctx: &SearchContext, page: usize, page_size: usize) -> SearchPage {
+        let ranked = self.ranked_matches(ctx);
+        let total = ranked.len();
+
+        let start = page.saturating_mul(page_size).min(total);
+        let end = start.saturating_add(page_size).min(total);
+
+        let results = ranked[start..end].iter().map(|(_, r)| r.clone()).collect();
+
+        SearchPage {
+            results,
+            page,
+            page_size,
+            total,
+            has_more: end < total,
+        }
+    }
+
+    /// Match every command against `ctx` under its search mode, frecency-rank
+    /// the matches and sort them best-first. Shared by `search` and `search_paged` so both page over
+    /// the exact same ranking.
+    fn ranked_matches(&self, ctx: &SearchContext) -> Vec<(i64, SearchResult)> {
         let mut results: Vec<(i64, SearchResult)> = Vec::new();
 
-        for cmd in COMMANDS {
-            let mut best_score: i64 = 0;
+        for cmd in &self.commands {
+            let mut best_score: u32 = 0;
 
             // Match against name
-            if let Some(score) = self.matcher.fuzzy_match(&cmd.name.to_lowercase(), &query_lower) {
+            if let Some(score) = ctx.score(&self.matcher, &cmd.name) {
                 best_score = best_score.max(score);
             }
 
             // Match against aliases
-            for alias in cmd.aliases {
-                if let Some(score) = self.matcher.fuzzy_match(&alias.to_lowercase(), &query_lower) {
+            for alias in &cmd.aliases {
+                if let Some(score) = ctx.score(&self.matcher, alias) {
                     best_score = best_score.max(score);
                 }
             }
 
             // Match against description
-            if let Some(score) = self.matcher.fuzzy_match(&cmd.description.to_lowercase(), &query_lower) {
+            if let Some(score) = ctx.score(&self.matcher, &cmd.description) {
                 best_score = best_score.max(score / 2); // Lower weight for description
             }
 
-            if best_score > 20 {
+            if best_score == 0 {
+                continue;
+            }
+
+            let frecency = self.frecency_boost(&cmd.command);
+            let final_score = best_score as i64 + frecency;
+
+            if final_score > 20 {
+                let kind = if cmd.shell {
+                    SearchResultKind::Shell {
+                        command: cmd.command.clone(),
+                    }
+                } else {
+                    SearchResultKind::Command {
+                        command: cmd.command.clone(),
+                    }
+                };
+
                 let result = SearchResult {
                     id: format!("cmd:{}", cmd.command),
-                    title: cmd.name.to_string(),
-                    subtitle: cmd.description.to_string(),
-                    icon: cmd.icon.to_string(),
+                    title: cmd.name.clone(),
+                    subtitle: cmd.description.clone(),
+                    icon: cmd.icon.clone(),
                     category: SearchCategory::Commands,
-                    kind: SearchResultKind::Command {
-                        command: cmd.command.to_string(),
-                    },
-                    score: best_score.min(100) as u32,
-                    from_history: false,
+                    kind,
+                    score: final_score.min(100) as u32,
+                    from_history: frecency > 0,
                 };
 
-                results.push((best_score, result));
+                results.push((final_score, result));
             }
         }
 
-        // Sort by score and take top results
+        // Sort by score, best first
         results.sort_by(|a, b| b.0.cmp(&a.0));
-        results.into_iter().take(3).map(|(_, r)| r).collect()
+        results
+    }
+}
+
+impl LauncherProvider for CommandSearcher {
+    /// Search for matching commands, returning up to `max_results` of them.
+    /// Use [`CommandSearcher::search_paged`] to page through the rest.
+    fn search(&self, ctx: &SearchContext) -> Vec<SearchResult> {
+        self.ranked_matches(ctx)
+            .into_iter()
+            .take(self.max_results)
+            .map(|(_, r)| r)
+            .collect()
     }
 }
 
+/// A single page of ranked command results, returned by
+/// [`CommandSearcher::search_paged`]
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    /// Results within this page's window
+    pub results: Vec<SearchResult>,
+    /// The page requested (0-indexed)
+    pub page: usize,
+    /// The page size requested
+    pub page_size: usize,
+    /// Total number of matches across all pages
+    pub total: usize,
+    /// Whether a further page has more results
+    pub has_more: bool,
+}
+
 impl Default for CommandSearcher {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Convert the built-in `'static` command table into owned `SystemCommand`s
+fn builtin_commands() -> Vec<SystemCommand> {
+    COMMANDS
+        .iter()
+        .map(|cmd| SystemCommand {
+            name: cmd.name.to_string(),
+            aliases: cmd.aliases.iter().map(|a| a.to_string()).collect(),
+            description: cmd.description.to_string(),
+            icon: cmd.icon.to_string(),
+            command: cmd.command.to_string(),
+            dangerous: cmd.dangerous,
+            shell: false,
+        })
+        .collect()
+}
+
+/// Load and validate user-defined commands from `commands.toml`, logging and
+/// skipping any malformed entry instead of panicking. Returns an empty list
+/// if the file doesn't exist or fails to parse.
+fn load_user_commands() -> Vec<SystemCommand> {
+    let path = user_commands_path();
+
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+
+    let file: UserCommandsFile = match toml::from_str(&contents) {
+        Ok(file) => file,
+        Err(e) => {
+            warn!("Failed to parse {}: {}", path.display(), e);
+            return Vec::new();
+        }
+    };
+
+    file.command
+        .into_iter()
+        .filter_map(|entry| {
+            if entry.name.trim().is_empty() || entry.command.trim().is_empty() {
+                warn!(
+                    "Skipping command entry with empty name/command in {}",
+                    path.display()
+                );
+                return None;
+            }
+
+            Some(SystemCommand {
+                name: entry.name,
+                aliases: entry.aliases,
+                description: entry.description,
+                icon: entry.icon,
+                command: entry.command,
+                dangerous: entry.dangerous,
+                shell: entry.shell,
+            })
+        })
+        .collect()
+}
+
+/// Path to the user commands file, `~/.config/winux/commands.toml`
+fn user_commands_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("winux")
+        .join("commands.toml")
+}
+
+/// Recorded usage for a single command, keyed by its `command` string
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct UsageEntry {
+    count: u32,
+    last_used_secs: u64,
+}
+
+type UsageMap = HashMap<String, UsageEntry>;
+
+/// Path to the persisted usage store
+fn usage_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("winux-launcher")
+        .join("command_usage.json")
+}
+
+/// Load the persisted usage store, logging and falling back to an empty map
+/// if the file is missing or malformed
+fn load_usage() -> UsageMap {
+    let path = usage_path();
+
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return UsageMap::new();
+    };
+
+    match serde_json::from_str(&contents) {
+        Ok(usage) => usage,
+        Err(e) => {
+            warn!("Failed to parse {}: {}", path.display(), e);
+            UsageMap::new()
+        }
+    }
+}
+
+/// Persist the usage store, logging (rather than panicking) on I/O failure
+fn save_usage(usage: &UsageMap) {
+    let path = usage_path();
+
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            warn!("Failed to create {}: {}", parent.display(), e);
+            return;
+        }
+    }
+
+    match serde_json::to_string_pretty(usage) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(&path, json) {
+                warn!("Failed to write {}: {}", path.display(), e);
+            }
+        }
+        Err(e) => warn!("Failed to serialize command usage: {}", e),
+    }
+}
+
+fn now_unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -1,9 +1,8 @@
 //! File search provider
 
 use crate::config::Config;
-use crate::search::{SearchCategory, SearchResult, SearchResultKind};
+use crate::search::{LauncherProvider, SearchCategory, SearchContext, SearchResult, SearchResultKind};
 use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::Arc;
@@ -46,27 +45,14 @@ impl FileSearcher {
         }
     }
 
-    /// Search for files
-    pub fn search(&self, query: &str) -> Vec<SearchResult> {
-        if query.len() < 2 {
-            return vec![];
-        }
-
-        if self.use_locate {
-            self.search_with_locate(query)
-        } else {
-            self.search_fallback(query)
-        }
-    }
-
     /// Search using locate command
-    fn search_with_locate(&self, query: &str) -> Vec<SearchResult> {
+    fn search_with_locate(&self, ctx: &SearchContext) -> Vec<SearchResult> {
         let output = Command::new("locate")
             .arg("-i") // Case insensitive
             .arg("-l")
             .arg("20") // Limit results
             .arg("--regex")
-            .arg(format!(".*{}.*", regex::escape(query)))
+            .arg(format!(".*{}.*", regex::escape(&ctx.query)))
             .output();
 
         match output {
@@ -76,7 +62,7 @@ impl FileSearcher {
                     .lines()
                     .filter(|line| !line.is_empty())
                     .filter(|line| self.is_valid_path(line))
-                    .filter_map(|line| self.path_to_result(PathBuf::from(line), query))
+                    .filter_map(|line| self.path_to_result(PathBuf::from(line), ctx))
                     .take(5)
                     .collect()
             }
@@ -88,9 +74,8 @@ impl FileSearcher {
     }
 
     /// Fallback search (basic file system search)
-    fn search_fallback(&self, query: &str) -> Vec<SearchResult> {
+    fn search_fallback(&self, ctx: &SearchContext) -> Vec<SearchResult> {
         let mut results = Vec::new();
-        let query_lower = query.to_lowercase();
 
         // Search in home directory and common locations
         let search_paths = vec![
@@ -110,13 +95,13 @@ impl FileSearcher {
                         .unwrap_or_default();
 
                     // Skip hidden files unless query starts with .
-                    if name.starts_with('.') && !query.starts_with('.') {
+                    if name.starts_with('.') && !ctx.query.starts_with('.') {
                         continue;
                     }
 
-                    if let Some(score) = self.matcher.fuzzy_match(&name.to_lowercase(), &query_lower) {
+                    if let Some(score) = ctx.score(&self.matcher, &name) {
                         if score > 0 {
-                            if let Some(result) = self.path_to_result(path, query) {
+                            if let Some(result) = self.path_to_result(path, ctx) {
                                 results.push((score, result));
                             }
                         }
@@ -146,18 +131,14 @@ impl FileSearcher {
     }
 
     /// Convert path to search result
-    fn path_to_result(&self, path: PathBuf, query: &str) -> Option<SearchResult> {
+    fn path_to_result(&self, path: PathBuf, ctx: &SearchContext) -> Option<SearchResult> {
         let name = path.file_name()?.to_string_lossy().to_string();
 
         // Determine icon based on file type
         let icon = self.get_file_icon(&path);
 
         // Calculate score based on name match
-        let score = self
-            .matcher
-            .fuzzy_match(&name.to_lowercase(), &query.to_lowercase())
-            .unwrap_or(0)
-            .min(100) as u32;
+        let score = ctx.score(&self.matcher, &name).unwrap_or(0).min(100);
 
         // Get parent directory for subtitle
         let subtitle = path
@@ -223,3 +204,18 @@ impl FileSearcher {
         .to_string()
     }
 }
+
+impl LauncherProvider for FileSearcher {
+    /// Search for files
+    fn search(&self, ctx: &SearchContext) -> Vec<SearchResult> {
+        if ctx.query.len() < 2 {
+            return vec![];
+        }
+
+        if self.use_locate {
+            self.search_with_locate(ctx)
+        } else {
+            self.search_fallback(ctx)
+        }
+    }
+}
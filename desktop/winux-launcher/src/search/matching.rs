@@ -0,0 +1,61 @@
+//! Shared string matching helpers for `LauncherProvider`s
+//!
+//! Each helper scores a single haystack against a query under one
+//! `SearchMode` and returns `None` for no match, else `Some(score)`.
+//! `substring_match` and `regex_match` score within `0..=100`;
+//! `fuzzy_match` returns skim's unscaled score, which can exceed 100.
+//! Providers call these (usually via
+//! `SearchContext::score`) instead of reimplementing matching themselves.
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use regex::Regex;
+
+/// Fuzzy subsequence match via `skim`, case-insensitive
+pub fn fuzzy_match(matcher: &SkimMatcherV2, haystack: &str, needle: &str) -> Option<u32> {
+    matcher
+        .fuzzy_match(&haystack.to_lowercase(), &needle.to_lowercase())
+        .map(|score| score.max(0) as u32)
+}
+
+/// Literal substring match, optionally case-sensitive and/or restricted to
+/// whole-word boundaries. Scores a bit higher for a prefix match and for
+/// needles that cover more of the haystack.
+pub fn substring_match(haystack: &str, needle: &str, case_sensitive: bool, whole_word: bool) -> Option<u32> {
+    if needle.is_empty() {
+        return None;
+    }
+
+    let haystack_owned;
+    let needle_owned;
+    let (haystack, needle): (&str, &str) = if case_sensitive {
+        (haystack, needle)
+    } else {
+        haystack_owned = haystack.to_lowercase();
+        needle_owned = needle.to_lowercase();
+        (&haystack_owned, &needle_owned)
+    };
+
+    if whole_word {
+        let matches = haystack
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| word == needle);
+        return matches.then_some(100);
+    }
+
+    let idx = haystack.find(needle)?;
+    let prefix_bonus = if idx == 0 { 30 } else { 0 };
+    let coverage = ((needle.len() as f64 / haystack.len().max(1) as f64) * 20.0) as u32;
+    Some((50 + prefix_bonus + coverage).min(100))
+}
+
+/// Compiled-regex match, scored by how much of `haystack` the matches cover
+pub fn regex_match(haystack: &str, pattern: &Regex) -> Option<u32> {
+    let matched_len: usize = pattern.find_iter(haystack).map(|m| m.len()).sum();
+    if matched_len == 0 {
+        return None;
+    }
+
+    let coverage = (matched_len as f64 / haystack.len().max(1) as f64).min(1.0);
+    Some((50.0 + coverage * 50.0) as u32)
+}
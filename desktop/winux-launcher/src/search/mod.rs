@@ -4,21 +4,108 @@ pub mod apps;
 pub mod calculator;
 pub mod commands;
 pub mod files;
+pub mod matching;
 pub mod plugins;
 pub mod web;
 
 use crate::config::Config;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use regex::Regex;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::debug;
 
 pub use apps::AppSearcher;
 pub use calculator::Calculator;
-pub use commands::CommandSearcher;
+pub use commands::{CommandSearcher, SearchPage};
 pub use files::FileSearcher;
+pub use matching::{fuzzy_match, regex_match, substring_match};
 pub use plugins::PluginManager;
 pub use web::WebSearcher;
 
+/// How a `LauncherProvider` should interpret the query string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Skim-style fuzzy subsequence matching (the historical default)
+    Fuzzy,
+    /// Literal substring matching
+    Substring,
+    /// Match against a compiled regular expression
+    Regex,
+}
+
+impl Default for SearchMode {
+    fn default() -> Self {
+        Self::Fuzzy
+    }
+}
+
+/// A query plus the options controlling how providers match it
+///
+/// Built once per keystroke (or toggle) by the UI and handed to every
+/// `LauncherProvider`. When `mode` is `Regex`, the pattern is compiled once
+/// here rather than per-haystack; an invalid pattern simply matches nothing.
+#[derive(Debug, Clone)]
+pub struct SearchContext {
+    pub query: String,
+    pub mode: SearchMode,
+    pub case_sensitive: bool,
+    pub whole_word: bool,
+    regex: Option<Regex>,
+}
+
+impl SearchContext {
+    pub fn new(query: impl Into<String>, mode: SearchMode, case_sensitive: bool, whole_word: bool) -> Self {
+        let query = query.into();
+        let regex = (mode == SearchMode::Regex).then(|| {
+            if case_sensitive {
+                Regex::new(&query)
+            } else {
+                Regex::new(&format!("(?i){}", query))
+            }
+        }).and_then(Result::ok);
+
+        Self {
+            query,
+            mode,
+            case_sensitive,
+            whole_word,
+            regex,
+        }
+    }
+
+    /// Score `haystack` against this context's query, dispatching to
+    /// whichever matching helper corresponds to `mode`
+    pub fn score(&self, matcher: &SkimMatcherV2, haystack: &str) -> Option<u32> {
+        match self.mode {
+            SearchMode::Fuzzy => matching::fuzzy_match(matcher, haystack, &self.query),
+            SearchMode::Substring => {
+                matching::substring_match(haystack, &self.query, self.case_sensitive, self.whole_word)
+            }
+            SearchMode::Regex => self.regex.as_ref().and_then(|re| matching::regex_match(haystack, re)),
+        }
+    }
+}
+
+impl Default for SearchContext {
+    fn default() -> Self {
+        Self::new(String::new(), SearchMode::default(), false, false)
+    }
+}
+
+/// A provider the launcher's `SearchEngine` can query, decoupled from how it
+/// sources and scores its candidates
+pub trait LauncherProvider {
+    /// Search for matches against `ctx`
+    fn search(&self, ctx: &SearchContext) -> Vec<SearchResult>;
+
+    /// Which `SearchMode`s this provider understands, so the UI can grey out
+    /// toggles that some active provider does not support. Defaults to all of them.
+    fn supported_modes(&self) -> &'static [SearchMode] {
+        &[SearchMode::Fuzzy, SearchMode::Substring, SearchMode::Regex]
+    }
+}
+
 /// Search result representation
 #[derive(Debug, Clone)]
 pub struct SearchResult {
@@ -121,6 +208,9 @@ pub enum SearchResultKind {
     Command {
         command: String,
     },
+    Shell {
+        command: String,
+    },
     Plugin {
         plugin_id: String,
         action: String,
@@ -152,9 +242,9 @@ impl SearchEngine {
         }
     }
 
-    /// Perform search across all providers
-    pub fn search(&self, query: &str) -> Vec<SearchResult> {
-        let query = query.trim();
+    /// Perform search across all providers using the given `SearchContext`
+    pub fn search(&self, ctx: &SearchContext) -> Vec<SearchResult> {
+        let query = ctx.query.trim();
 
         if query.len() < self.config.search.min_query_length {
             return vec![];
@@ -183,17 +273,17 @@ impl SearchEngine {
 
         // System commands
         if self.config.search.commands_enabled {
-            results.extend(self.command_searcher.search(query));
+            results.extend(self.command_searcher.search(ctx));
         }
 
         // Applications
         if self.config.search.apps_enabled {
-            results.extend(self.app_searcher.search(query));
+            results.extend(self.app_searcher.search(ctx));
         }
 
         // Files
         if self.config.search.files_enabled {
-            results.extend(self.file_searcher.search(query));
+            results.extend(self.file_searcher.search(ctx));
         }
 
         // Plugins
@@ -226,10 +316,34 @@ impl SearchEngine {
         results
     }
 
+    /// Record that a search result was launched, so command results can be
+    /// ranked by frecency in future searches
+    pub fn record_use(&self, result: &SearchResult) {
+        match &result.kind {
+            SearchResultKind::Command { command } | SearchResultKind::Shell { command } => {
+                self.command_searcher.record_use(command);
+            }
+            _ => {}
+        }
+    }
+
     /// Refresh search indexes
     pub fn refresh(&mut self) {
         self.app_searcher.refresh();
         self.file_searcher.refresh();
         self.plugin_manager.refresh();
     }
+
+    /// `SearchMode`s every `LauncherProvider`-backed searcher supports, so
+    /// the UI can grey out a toggle that some provider can't honor
+    pub fn supported_modes(&self) -> Vec<SearchMode> {
+        let all = [SearchMode::Fuzzy, SearchMode::Substring, SearchMode::Regex];
+        all.into_iter()
+            .filter(|mode| {
+                self.app_searcher.supported_modes().contains(mode)
+                    && self.command_searcher.supported_modes().contains(mode)
+                    && self.file_searcher.supported_modes().contains(mode)
+            })
+            .collect()
+    }
 }
@@ -187,6 +187,9 @@ impl PreviewPanel {
             SearchResultKind::Command { command } => {
                 format!("System Command\n\nAction: {}", command)
             }
+            SearchResultKind::Shell { command } => {
+                format!("Shell Command\n\n{}", command)
+            }
             SearchResultKind::Plugin { plugin_id, action } => {
                 format!("Plugin: {}\n\nAction: {}", plugin_id, action)
             }
@@ -220,6 +223,9 @@ impl PreviewPanel {
             SearchResultKind::Command { .. } => {
                 self.add_action_button("Run", "system-run-symbolic");
             }
+            SearchResultKind::Shell { .. } => {
+                self.add_action_button("Run", "system-run-symbolic");
+            }
             SearchResultKind::Plugin { .. } => {
                 self.add_action_button("Execute", "system-run-symbolic");
             }
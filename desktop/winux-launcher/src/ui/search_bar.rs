@@ -1,7 +1,8 @@
 //! Search bar component
 
+use crate::search::SearchMode;
 use gtk4::prelude::*;
-use gtk4::{glib, Entry, Image, Box as GtkBox, Orientation};
+use gtk4::{glib, Entry, Image, Box as GtkBox, Orientation, ToggleButton};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -10,6 +11,11 @@ use std::rc::Rc;
 pub struct SearchBar {
     container: GtkBox,
     entry: Entry,
+    mode_fuzzy: ToggleButton,
+    mode_substring: ToggleButton,
+    mode_regex: ToggleButton,
+    case_sensitive_btn: ToggleButton,
+    whole_word_btn: ToggleButton,
     callbacks: Rc<RefCell<Vec<Box<dyn Fn(&str)>>>>,
 }
 
@@ -33,6 +39,42 @@ impl SearchBar {
         entry.add_css_class("search-entry");
         container.append(&entry);
 
+        // Search mode toggles: fuzzy (default), substring, regex
+        let mode_fuzzy = ToggleButton::builder()
+            .label("~")
+            .tooltip_text("Fuzzy match")
+            .active(true)
+            .build();
+        let mode_substring = ToggleButton::builder()
+            .label("abc")
+            .tooltip_text("Substring match")
+            .build();
+        mode_substring.set_group(Some(&mode_fuzzy));
+        let mode_regex = ToggleButton::builder()
+            .label(".*")
+            .tooltip_text("Regex match")
+            .build();
+        mode_regex.set_group(Some(&mode_fuzzy));
+        for btn in [&mode_fuzzy, &mode_substring, &mode_regex] {
+            btn.add_css_class("search-mode-toggle");
+            container.append(btn);
+        }
+
+        // Case-sensitive / whole-word modifiers, independent of mode
+        let case_sensitive_btn = ToggleButton::builder()
+            .label("Aa")
+            .tooltip_text("Case sensitive")
+            .build();
+        case_sensitive_btn.add_css_class("search-mode-toggle");
+        container.append(&case_sensitive_btn);
+
+        let whole_word_btn = ToggleButton::builder()
+            .label("\u{201c}\u{201d}")
+            .tooltip_text("Whole word")
+            .build();
+        whole_word_btn.add_css_class("search-mode-toggle");
+        container.append(&whole_word_btn);
+
         let callbacks: Rc<RefCell<Vec<Box<dyn Fn(&str)>>>> = Rc::new(RefCell::new(Vec::new()));
 
         // Connect text changed signal
@@ -44,9 +86,33 @@ impl SearchBar {
             }
         });
 
+        // Re-run the search whenever a mode/modifier toggle changes, using
+        // whatever text is currently in the entry
+        for btn in [
+            &mode_fuzzy,
+            &mode_substring,
+            &mode_regex,
+            &case_sensitive_btn,
+            &whole_word_btn,
+        ] {
+            let entry = entry.clone();
+            let callbacks = callbacks.clone();
+            btn.connect_toggled(move |_| {
+                let text = entry.text();
+                for callback in callbacks.borrow().iter() {
+                    callback(&text);
+                }
+            });
+        }
+
         Self {
             container,
             entry,
+            mode_fuzzy,
+            mode_substring,
+            mode_regex,
+            case_sensitive_btn,
+            whole_word_btn,
             callbacks,
         }
     }
@@ -85,6 +151,39 @@ impl SearchBar {
     pub fn select_all(&self) {
         self.entry.select_region(0, -1);
     }
+
+    /// The currently selected search mode
+    pub fn mode(&self) -> SearchMode {
+        if self.mode_substring.is_active() {
+            SearchMode::Substring
+        } else if self.mode_regex.is_active() {
+            SearchMode::Regex
+        } else {
+            SearchMode::Fuzzy
+        }
+    }
+
+    /// Whether the case-sensitive toggle is active
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive_btn.is_active()
+    }
+
+    /// Whether the whole-word toggle is active
+    pub fn whole_word(&self) -> bool {
+        self.whole_word_btn.is_active()
+    }
+
+    /// Build a `SearchContext` from the current entry text and toggle state
+    pub fn context(&self) -> crate::search::SearchContext {
+        crate::search::SearchContext::new(self.text(), self.mode(), self.case_sensitive(), self.whole_word())
+    }
+
+    /// Grey out mode toggles whose mode is not in `modes`
+    pub fn set_available_modes(&self, modes: &[SearchMode]) {
+        self.mode_fuzzy.set_sensitive(modes.contains(&SearchMode::Fuzzy));
+        self.mode_substring.set_sensitive(modes.contains(&SearchMode::Substring));
+        self.mode_regex.set_sensitive(modes.contains(&SearchMode::Regex));
+    }
 }
 
 impl Default for SearchBar {
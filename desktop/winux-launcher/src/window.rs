@@ -74,6 +74,7 @@ impl LauncherWindow {
 
         // Initialize search engine
         let search_engine = Rc::new(RefCell::new(SearchEngine::new(config.clone())));
+        search_bar.set_available_modes(&search_engine.borrow().supported_modes());
 
         // Setup key event controller
         let key_controller = gtk4::EventControllerKey::new();
@@ -109,6 +110,7 @@ impl LauncherWindow {
                 }
                 gdk::Key::Return | gdk::Key::KP_Enter => {
                     if let Some(result) = result_list_clone.selected() {
+                        search_engine_clone.borrow().record_use(&result);
                         Self::execute_result(&result, state.contains(gdk::ModifierType::SHIFT_MASK));
                         if config_clone.general.auto_hide {
                             if let Some(window) = window_weak.upgrade() {
@@ -134,10 +136,11 @@ impl LauncherWindow {
         let preview_panel_clone = preview_panel.clone();
         let search_engine_clone = search_engine.clone();
         let config_clone = config.clone();
+        let search_bar_ctx = search_bar.clone();
 
-        search_bar.connect_changed(move |query| {
+        search_bar.connect_changed(move |_query| {
             let engine = search_engine_clone.borrow();
-            let results = engine.search(&query);
+            let results = engine.search(&search_bar_ctx.context());
 
             result_list_clone.set_results(results.clone());
 
@@ -251,6 +254,12 @@ impl LauncherWindow {
             SearchResultKind::Command { command } => {
                 Self::execute_command(command);
             }
+            SearchResultKind::Shell { command } => {
+                let _ = std::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(command)
+                    .spawn();
+            }
             SearchResultKind::Plugin { plugin_id, action } => {
                 // TODO: Execute plugin action
                 debug!("Plugin action: {} - {}", plugin_id, action);
@@ -394,11 +394,33 @@ pub trait LauncherProvider: Send + Sync {
     }
 }
 
+/// Whether `c` is a word-boundary separator for fuzzy matching purposes
+fn is_word_separator(c: char) -> bool {
+    matches!(c, ' ' | '_' | '-' | '/' | '.')
+}
+
 /// Helper for fuzzy matching
+///
+/// Requires all query characters to appear in `text` in order (otherwise
+/// returns `None`), then scores the best alignment with an fzf-style DP:
+/// each matched character earns a base score plus bonuses for extending a
+/// consecutive run, landing on a word boundary (after a separator or at a
+/// camelCase transition), or being the very first character of `text`, with
+/// a penalty for gaps skipped between matches. The result is normalized
+/// against the theoretical best alignment for the query length, so a long
+/// fuzzy match can approach but not reach the exact/prefix/contains fast
+/// paths above it.
 pub fn fuzzy_match(query: &str, text: &str) -> Option<u32> {
+    // Keep the original casing around for camelCase boundary detection; all
+    // matching itself stays case-insensitive via the lowercased copies.
+    let orig_chars: Vec<char> = text.chars().collect();
     let query = query.to_lowercase();
     let text = text.to_lowercase();
 
+    if query.is_empty() {
+        return None;
+    }
+
     // Exact match = highest score
     if text == query {
         return Some(100);
@@ -414,31 +436,93 @@ pub fn fuzzy_match(query: &str, text: &str) -> Option<u32> {
         return Some(70);
     }
 
-    // Fuzzy match (all query chars appear in order)
-    let mut query_chars = query.chars().peekable();
-    let mut score = 0u32;
-    let mut consecutive = 0;
+    let text_chars: Vec<char> = text.chars().collect();
+    let query_chars: Vec<char> = query.chars().collect();
+    let n = text_chars.len();
 
-    for c in text.chars() {
-        if let Some(&qc) = query_chars.peek() {
-            if c == qc {
-                query_chars.next();
-                consecutive += 1;
-                score += 10 + consecutive * 5;
-            } else {
-                consecutive = 0;
-            }
+    let boundary_bonus = |j: usize| -> i32 {
+        let cur = orig_chars.get(j).copied().unwrap_or(text_chars[j]);
+        let prev = j.checked_sub(1).and_then(|k| orig_chars.get(k).copied());
+        let is_boundary = j == 0
+            || prev.is_some_and(is_word_separator)
+            || (cur.is_uppercase() && prev.is_some_and(|c| c.is_lowercase()));
+        if is_boundary {
+            15
         } else {
-            break;
+            0
         }
-    }
+    };
 
-    if query_chars.peek().is_none() {
-        // All query chars matched
-        Some(score.min(60))
-    } else {
-        None
-    }
+    // dp[j] = best score of an alignment of query[0..=i] that ends with
+    // query[i] matched at text[j], together with the length of the
+    // consecutive run ending there. `None` means no valid alignment ends at j.
+    let mut dp: Vec<Option<(i32, u32)>> = vec![None; n];
+
+    for (i, &qc) in query_chars.iter().enumerate() {
+        let mut next_dp: Vec<Option<(i32, u32)>> = vec![None; n];
+
+        for j in 0..n {
+            if text_chars[j] != qc {
+                continue;
+            }
+
+            if i == 0 {
+                let first_char_bonus = if j == 0 { 15 } else { 0 };
+                next_dp[j] = Some((10 + boundary_bonus(j) + first_char_bonus, 1));
+                continue;
+            }
+
+            // Extend from the best alignment ending before this position,
+            // penalizing however many characters were skipped to get here.
+            let mut best: Option<(i32, u32)> = None;
+            for k in 0..j {
+                let Some((prev_score, prev_run)) = dp[k] else {
+                    continue;
+                };
+                let consecutive = k + 1 == j;
+                let run = if consecutive { prev_run + 1 } else { 1 };
+                let consecutive_bonus = if consecutive { 5 + run as i32 * 5 } else { 0 };
+                let gap_penalty = if consecutive {
+                    0
+                } else {
+                    ((j - k - 1) as i32 * 2).min(20)
+                };
+                let score = prev_score + 10 + boundary_bonus(j) + consecutive_bonus - gap_penalty;
+
+                if best.map_or(true, |(b, _)| score > b) {
+                    best = Some((score, run));
+                }
+            }
+
+            next_dp[j] = best;
+        }
+
+        if next_dp.iter().all(Option::is_none) {
+            // Query char `i` never matched after the alignments so far, so
+            // the query does not appear in order in `text`.
+            return None;
+        }
+        dp = next_dp;
+    }
+
+    let best_score = dp
+        .iter()
+        .filter_map(|entry| entry.map(|(score, _)| score))
+        .max()?;
+
+    // Theoretical best score for a query of this length: every char
+    // consecutive, every char on a word boundary, first char bonus included.
+    let max_possible: i32 = (0..query_chars.len())
+        .map(|idx| {
+            let run = idx as i32 + 1;
+            let first_char_bonus = if idx == 0 { 15 } else { 0 };
+            let consecutive_bonus = if idx > 0 { 5 + run * 5 } else { 0 };
+            10 + 15 + first_char_bonus + consecutive_bonus
+        })
+        .sum();
+
+    let normalized = (best_score.max(0) as f64 / max_possible.max(1) as f64) * 60.0;
+    Some((normalized as u32).min(60))
 }
 
 #[cfg(test)]
@@ -470,6 +554,24 @@ mod tests {
         assert!(fuzzy_match("xyz", "testing").is_none());
     }
 
+    #[test]
+    fn test_fuzzy_match_prefers_word_boundaries() {
+        // "gc" lands on a word boundary in both words of "git-commit" but is
+        // buried mid-word in "megacorp", so the former should score higher.
+        let boundary = fuzzy_match("gc", "git-commit").unwrap();
+        let mid_word = fuzzy_match("gc", "megacorp").unwrap();
+        assert!(boundary > mid_word, "{boundary} should be > {mid_word}");
+    }
+
+    #[test]
+    fn test_fuzzy_match_prefers_camel_case_boundaries() {
+        // "gc" lands on the camelCase boundary in "gitCommit" but is buried
+        // mid-word in "megacorp", so the former should score higher.
+        let boundary = fuzzy_match("gc", "gitCommit").unwrap();
+        let mid_word = fuzzy_match("gc", "megacorp").unwrap();
+        assert!(boundary > mid_word, "{boundary} should be > {mid_word}");
+    }
+
     #[test]
     fn test_search_result_builder() {
         let result = SearchResult::new("test", "Test")